@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+
+const OLLAMA_BASE: &str = "http://localhost:11434";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorkloadPrompt {
+    pub prompt: String,
+    #[serde(default = "default_max_tokens")]
+    pub max_tokens: u32,
+}
+
+fn default_max_tokens() -> u32 {
+    256
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorkloadFile {
+    pub name: String,
+    pub model: String,
+    #[serde(default = "default_iterations")]
+    pub iterations: u32,
+    pub prompts: Vec<WorkloadPrompt>,
+}
+
+fn default_iterations() -> u32 {
+    3
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateResponse {
+    #[serde(default)]
+    eval_count: u64,
+    #[serde(default)]
+    eval_duration: u64,
+    #[serde(default)]
+    prompt_eval_count: u64,
+    #[serde(default)]
+    prompt_eval_duration: u64,
+    #[serde(default)]
+    total_duration: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PromptSample {
+    pub tokens_per_sec: f64,
+    pub time_to_first_token_ms: f64,
+    pub total_duration_ms: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PromptResult {
+    pub prompt: String,
+    pub mean_tokens_per_sec: f64,
+    pub median_tokens_per_sec: f64,
+    pub p95_tokens_per_sec: f64,
+    pub mean_ttft_ms: f64,
+    pub samples: Vec<PromptSample>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BenchmarkResult {
+    pub name: String,
+    pub model: String,
+    pub timestamp: String,
+    pub system: crate::commands::onboarding::SystemInfo,
+    pub prompt_results: Vec<PromptResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BenchmarkRegression {
+    pub prompt: String,
+    pub baseline_tokens_per_sec: f64,
+    pub candidate_tokens_per_sec: f64,
+    pub percent_change: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BenchmarkComparison {
+    pub baseline_name: String,
+    pub candidate_name: String,
+    pub regressions: Vec<BenchmarkRegression>,
+}
+
+/// Load a workload file from disk and parse it into a `WorkloadFile`
+pub fn load_workload(path: &str) -> Result<WorkloadFile, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read workload file: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse workload file: {}", e))
+}
+
+/// Run a single prompt/iteration against Ollama and derive timing samples
+async fn sample_prompt(model: &str, prompt: &WorkloadPrompt) -> Result<PromptSample, String> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/generate", OLLAMA_BASE);
+
+    let body = serde_json::json!({
+        "model": model,
+        "prompt": prompt.prompt,
+        "options": { "num_predict": prompt.max_tokens },
+        "stream": false,
+    });
+
+    let resp = client
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+    let gen: GenerateResponse = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse generate response: {}", e))?;
+
+    let tokens_per_sec = if gen.eval_duration > 0 {
+        gen.eval_count as f64 / (gen.eval_duration as f64 / 1_000_000_000.0)
+    } else {
+        0.0
+    };
+
+    Ok(PromptSample {
+        tokens_per_sec,
+        time_to_first_token_ms: gen.prompt_eval_duration as f64 / 1_000_000.0,
+        total_duration_ms: gen.total_duration as f64 / 1_000_000.0,
+    })
+}
+
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() - 1) as f64 * pct).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Run a benchmark workload against a local Ollama model and write the results to ALFRED_HOME
+pub async fn run_benchmark(
+    workload: &WorkloadFile,
+    report_url: Option<&str>,
+) -> Result<BenchmarkResult, String> {
+    let system = crate::commands::onboarding::detect_system().await?;
+
+    let mut prompt_results = Vec::new();
+    for prompt in &workload.prompts {
+        let mut samples = Vec::new();
+        for _ in 0..workload.iterations {
+            samples.push(sample_prompt(&workload.model, prompt).await?);
+        }
+
+        let mut rates: Vec<f64> = samples.iter().map(|s| s.tokens_per_sec).collect();
+        rates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mean_tokens_per_sec = rates.iter().sum::<f64>() / rates.len() as f64;
+        let mean_ttft_ms = samples.iter().map(|s| s.time_to_first_token_ms).sum::<f64>()
+            / samples.len() as f64;
+
+        prompt_results.push(PromptResult {
+            prompt: prompt.prompt.clone(),
+            mean_tokens_per_sec,
+            median_tokens_per_sec: median(&rates),
+            p95_tokens_per_sec: percentile(&rates, 0.95),
+            mean_ttft_ms,
+            samples,
+        });
+    }
+
+    let result = BenchmarkResult {
+        name: workload.name.clone(),
+        model: workload.model.clone(),
+        timestamp: now_timestamp(),
+        system,
+        prompt_results,
+    };
+
+    write_result(&result)?;
+
+    if let Some(url) = report_url {
+        let client = reqwest::Client::new();
+        let _ = client.post(url).json(&result).send().await;
+    }
+
+    Ok(result)
+}
+
+fn now_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+fn write_result(result: &BenchmarkResult) -> Result<(), String> {
+    let bench_dir = crate::config::get_alfred_home().join("bench");
+    std::fs::create_dir_all(&bench_dir)
+        .map_err(|e| format!("Failed to create bench directory: {}", e))?;
+
+    let path = bench_dir.join(format!("{}-{}.json", result.name, result.timestamp));
+    let content = serde_json::to_string_pretty(result)
+        .map_err(|e| format!("Failed to serialize benchmark result: {}", e))?;
+
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write benchmark result: {}", e))
+}
+
+/// Diff two result files and flag tokens/sec regressions beyond a threshold (as a fraction, e.g. 0.1 for 10%)
+pub fn compare_benchmarks(
+    baseline_path: &str,
+    candidate_path: &str,
+    threshold: f64,
+) -> Result<BenchmarkComparison, String> {
+    let baseline = read_result(baseline_path)?;
+    let candidate = read_result(candidate_path)?;
+
+    let mut regressions = Vec::new();
+    for base_prompt in &baseline.prompt_results {
+        if let Some(cand_prompt) = candidate
+            .prompt_results
+            .iter()
+            .find(|p| p.prompt == base_prompt.prompt)
+        {
+            if base_prompt.mean_tokens_per_sec <= 0.0 {
+                continue;
+            }
+            let percent_change = (cand_prompt.mean_tokens_per_sec - base_prompt.mean_tokens_per_sec)
+                / base_prompt.mean_tokens_per_sec;
+
+            if percent_change < -threshold {
+                regressions.push(BenchmarkRegression {
+                    prompt: base_prompt.prompt.clone(),
+                    baseline_tokens_per_sec: base_prompt.mean_tokens_per_sec,
+                    candidate_tokens_per_sec: cand_prompt.mean_tokens_per_sec,
+                    percent_change: percent_change * 100.0,
+                });
+            }
+        }
+    }
+
+    Ok(BenchmarkComparison {
+        baseline_name: baseline.name,
+        candidate_name: candidate.name,
+        regressions,
+    })
+}
+
+fn read_result(path: &str) -> Result<BenchmarkResult, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read benchmark result: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse benchmark result: {}", e))
+}
@@ -0,0 +1,200 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::agents::AgentInfo;
+use crate::commands::onboarding::ModelRecommendation;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AgentListResult {
+    pub agents: Vec<AgentInfo>,
+    pub stale: bool,
+}
+
+fn db_path() -> std::path::PathBuf {
+    crate::config::get_alfred_home().join("cache.db")
+}
+
+fn open() -> Result<Connection, String> {
+    let home = crate::config::get_alfred_home();
+    std::fs::create_dir_all(&home).map_err(|e| format!("Failed to create ALFRED_HOME: {}", e))?;
+
+    let conn = Connection::open(db_path()).map_err(|e| format!("Failed to open cache db: {}", e))?;
+    init_db(&conn)?;
+    Ok(conn)
+}
+
+fn init_db(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS model_catalog (
+            name TEXT PRIMARY KEY,
+            display_name TEXT NOT NULL,
+            description TEXT NOT NULL,
+            size_gb REAL NOT NULL,
+            min_vram_mb INTEGER NOT NULL,
+            capabilities TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS agent_cache (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            data TEXT NOT NULL,
+            cached_at INTEGER NOT NULL
+        );",
+    )
+    .map_err(|e| format!("Failed to initialize cache db: {}", e))?;
+
+    seed_default_catalog(conn)
+}
+
+/// Populate the catalog with the built-in model tiers the first time the db is created
+fn seed_default_catalog(conn: &Connection) -> Result<(), String> {
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM model_catalog", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to check model catalog: {}", e))?;
+
+    if count > 0 {
+        return Ok(());
+    }
+
+    let seed = [
+        ("qwen2.5:32b", "Qwen 2.5 32B", "Powerful reasoning model, excellent for complex tasks", 19.0, 24000),
+        ("deepseek-r1:14b", "DeepSeek R1 14B", "Strong reasoning with chain-of-thought", 9.0, 24000),
+        ("qwen2.5:14b", "Qwen 2.5 14B", "Balanced performance and quality", 9.0, 8000),
+        ("llama3.1:8b", "Llama 3.1 8B", "Fast and efficient general-purpose model", 4.7, 8000),
+        ("qwen2.5:7b", "Qwen 2.5 7B", "Good balance of speed and capability", 4.4, 4000),
+        ("phi3:mini", "Phi-3 Mini", "Compact but capable model from Microsoft", 2.3, 4000),
+        ("qwen2.5:3b", "Qwen 2.5 3B", "Lightweight model that runs on CPU", 1.9, 0),
+        ("tinyllama", "TinyLlama", "Extremely lightweight for basic tasks", 0.6, 0),
+    ];
+
+    for (name, display_name, description, size_gb, min_vram_mb) in seed {
+        conn.execute(
+            "INSERT INTO model_catalog (name, display_name, description, size_gb, min_vram_mb, capabilities)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![name, display_name, description, size_gb, min_vram_mb, "[]"],
+        )
+        .map_err(|e| format!("Failed to seed model catalog: {}", e))?;
+    }
+
+    Ok(())
+}
+
+type CatalogRow = (String, String, String, f64, i64);
+
+/// Run one of the two catalog queries and collect its rows
+fn query_catalog(conn: &Connection, query: &str, budget_mb: u64) -> Result<Vec<CatalogRow>, String> {
+    let mut stmt = conn
+        .prepare(query)
+        .map_err(|e| format!("Failed to query model catalog: {}", e))?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![budget_mb as i64], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, f64>(3)?,
+                row.get::<_, i64>(4)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to read model catalog: {}", e))?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .map_err(|e| format!("Failed to read model catalog row: {}", e))
+}
+
+const CPU_TIER_QUERY: &str = "SELECT name, display_name, description, size_gb, min_vram_mb
+     FROM model_catalog
+     WHERE min_vram_mb = 0 AND CAST(size_gb * 1024 AS INTEGER) <= ?1
+     ORDER BY size_gb DESC
+     LIMIT 2";
+
+/// Query the catalog and return recommendations filtered by available VRAM/RAM.
+///
+/// A GPU budget only ever makes sense on a machine that actually has a GPU — a CPU-only
+/// box can't load a model into VRAM it doesn't have, no matter how much system RAM it has
+/// spare. So the two cases use entirely different tiers and budgets: VRAM-tiered models
+/// (`min_vram_mb > 0`) gated on `gpu_vram_mb`, or the CPU-capable tier (`min_vram_mb = 0`)
+/// gated on how much of the model's weights actually fit in RAM. A GPU too small for any
+/// VRAM-tiered model (the seeded catalog starts at 4000 MB) falls back to the CPU tier
+/// rather than leaving the user with no recommendation at all.
+pub fn get_recommendations(
+    gpu_vram_mb: u64,
+    ram_available_mb: u64,
+) -> Result<Vec<ModelRecommendation>, String> {
+    let conn = open()?;
+
+    let (mut rows, mut budget_mb) = if gpu_vram_mb > 0 {
+        (
+            query_catalog(
+                &conn,
+                "SELECT name, display_name, description, size_gb, min_vram_mb
+                 FROM model_catalog
+                 WHERE min_vram_mb > 0 AND min_vram_mb <= ?1
+                 ORDER BY min_vram_mb DESC
+                 LIMIT 2",
+                gpu_vram_mb,
+            )?,
+            gpu_vram_mb,
+        )
+    } else {
+        (Vec::new(), ram_available_mb)
+    };
+
+    if rows.is_empty() {
+        rows = query_catalog(&conn, CPU_TIER_QUERY, ram_available_mb)?;
+        budget_mb = ram_available_mb;
+    }
+
+    let mut recommendations = Vec::new();
+    for (i, (name, display_name, description, size_gb, _min_vram_mb)) in rows.into_iter().enumerate() {
+        recommendations.push(ModelRecommendation {
+            model_name: name,
+            display_name,
+            description,
+            size_gb,
+            recommended: i == 0,
+            reason: if i == 0 {
+                format!("Best fit for {} MB of available memory", budget_mb)
+            } else {
+                "Lighter alternative also within your hardware budget".to_string()
+            },
+        });
+    }
+
+    Ok(recommendations)
+}
+
+/// Cache the last-known agent list so the UI has something to show when the gateway is down
+pub fn cache_agents(agents: &[AgentInfo]) -> Result<(), String> {
+    let conn = open()?;
+    let data = serde_json::to_string(agents).map_err(|e| format!("Failed to serialize agents: {}", e))?;
+    let cached_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    conn.execute(
+        "INSERT INTO agent_cache (id, data, cached_at) VALUES (1, ?1, ?2)
+         ON CONFLICT(id) DO UPDATE SET data = excluded.data, cached_at = excluded.cached_at",
+        rusqlite::params![data, cached_at],
+    )
+    .map_err(|e| format!("Failed to cache agents: {}", e))?;
+
+    Ok(())
+}
+
+/// Read the cached agent list, if any
+pub fn get_cached_agents() -> Result<Option<Vec<AgentInfo>>, String> {
+    let conn = open()?;
+    let result: rusqlite::Result<String> =
+        conn.query_row("SELECT data FROM agent_cache WHERE id = 1", [], |row| row.get(0));
+
+    match result {
+        Ok(data) => {
+            let agents: Vec<AgentInfo> =
+                serde_json::from_str(&data).map_err(|e| format!("Failed to parse cached agents: {}", e))?;
+            Ok(Some(agents))
+        }
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(format!("Failed to read cached agents: {}", e)),
+    }
+}
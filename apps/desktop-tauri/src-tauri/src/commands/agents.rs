@@ -23,7 +23,7 @@ pub struct AgentInfo {
 }
 
 #[tauri::command]
-pub async fn list_agents() -> Result<Vec<AgentInfo>, String> {
+pub async fn list_agents() -> Result<crate::cache::AgentListResult, String> {
     let client = reqwest::Client::new();
     let resp = client
         .get("http://127.0.0.1:18789/api/agents")
@@ -32,12 +32,20 @@ pub async fn list_agents() -> Result<Vec<AgentInfo>, String> {
 
     match resp {
         Ok(r) if r.status().is_success() => {
-            r.json::<Vec<AgentInfo>>()
+            let agents: Vec<AgentInfo> = r
+                .json()
                 .await
-                .map_err(|e| format!("Failed to parse agents: {}", e))
+                .map_err(|e| format!("Failed to parse agents: {}", e))?;
+
+            let _ = crate::cache::cache_agents(&agents);
+            Ok(crate::cache::AgentListResult { agents, stale: false })
         }
         Ok(r) => Err(format!("Gateway returned status: {}", r.status())),
-        Err(_) => Ok(Vec::new()),
+        Err(_) => {
+            // Gateway unreachable - fall back to the last-known agent list
+            let agents = crate::cache::get_cached_agents()?.unwrap_or_default();
+            Ok(crate::cache::AgentListResult { agents, stale: true })
+        }
     }
 }
 
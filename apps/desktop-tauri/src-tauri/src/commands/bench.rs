@@ -0,0 +1,19 @@
+use crate::bench::{BenchmarkComparison, BenchmarkResult};
+
+#[tauri::command]
+pub async fn run_benchmark(
+    workload_path: String,
+    report_url: Option<String>,
+) -> Result<BenchmarkResult, String> {
+    let workload = crate::bench::load_workload(&workload_path)?;
+    crate::bench::run_benchmark(&workload, report_url.as_deref()).await
+}
+
+#[tauri::command]
+pub async fn compare_benchmarks(
+    baseline_path: String,
+    candidate_path: String,
+    threshold: f64,
+) -> Result<BenchmarkComparison, String> {
+    crate::bench::compare_benchmarks(&baseline_path, &candidate_path, threshold)
+}
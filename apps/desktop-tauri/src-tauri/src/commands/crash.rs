@@ -0,0 +1,11 @@
+use crate::crash::CrashReportSummary;
+
+#[tauri::command]
+pub fn list_crash_reports() -> Result<Vec<CrashReportSummary>, String> {
+    crate::crash::list_crash_reports()
+}
+
+#[tauri::command]
+pub async fn upload_crash_report(file_name: String) -> Result<String, String> {
+    crate::crash::upload_crash_report(&file_name).await
+}
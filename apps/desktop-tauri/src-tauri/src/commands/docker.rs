@@ -0,0 +1,54 @@
+use tauri::State;
+
+use crate::docker::{ContainerDetail, ContainerInfo, ContainerSpec};
+use crate::workers::{Tranquility, Worker, WorkerRegistry};
+
+#[tauri::command]
+pub async fn list_containers(name_filter: Option<String>) -> Result<Vec<ContainerInfo>, String> {
+    crate::docker::list_containers(name_filter.as_deref()).await
+}
+
+/// Provision (create + start) a container as a background worker and return its worker
+/// name immediately; progress is queryable via `workers::list_workers`.
+#[tauri::command]
+pub async fn start_container(registry: State<'_, WorkerRegistry>, spec: ContainerSpec) -> Result<String, String> {
+    let worker = crate::workers::ContainerProvisionWorker::new(spec);
+    let worker_name = worker.name();
+    crate::workers::spawn_worker(&registry, Box::new(worker), Tranquility::from_config()).await;
+    Ok(worker_name)
+}
+
+#[tauri::command]
+pub async fn stop_container(name: String) -> Result<String, String> {
+    crate::docker::stop_container(&name).await
+}
+
+#[tauri::command]
+pub async fn restart_container(name: String) -> Result<String, String> {
+    crate::docker::restart_container(&name).await
+}
+
+#[tauri::command]
+pub async fn inspect_container(name: String) -> Result<ContainerDetail, String> {
+    crate::docker::inspect_container(&name).await
+}
+
+#[tauri::command]
+pub async fn container_logs(name: String, tail: Option<u32>) -> Result<String, String> {
+    crate::docker::container_logs(&name, tail).await
+}
+
+#[tauri::command]
+pub async fn stream_container_stats(app: tauri::AppHandle, name: String) -> Result<(), String> {
+    crate::docker::stream_stats(&app, &name).await
+}
+
+#[tauri::command]
+pub async fn start_searxng() -> Result<String, String> {
+    crate::docker::start_searxng().await
+}
+
+#[tauri::command]
+pub async fn stop_searxng() -> Result<String, String> {
+    crate::docker::stop_searxng().await
+}
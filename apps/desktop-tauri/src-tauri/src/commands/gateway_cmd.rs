@@ -1,4 +1,4 @@
-use tauri::State;
+use tauri::{AppHandle, State};
 use serde::{Deserialize, Serialize};
 use crate::gateway::GatewayState;
 
@@ -8,11 +8,13 @@ pub struct GatewayStatus {
     pub port: u16,
     pub health: String,
     pub logs: Vec<String>,
+    pub service_installed: bool,
+    pub auto_start_enabled: bool,
 }
 
 #[tauri::command]
-pub async fn start_gateway(state: State<'_, GatewayState>) -> Result<String, String> {
-    crate::gateway::start_gateway(&state).await?;
+pub async fn start_gateway(app: AppHandle, state: State<'_, GatewayState>) -> Result<String, String> {
+    crate::gateway::start_gateway(&state, &app).await?;
     Ok("Gateway started successfully".to_string())
 }
 
@@ -25,12 +27,20 @@ pub async fn stop_gateway(state: State<'_, GatewayState>) -> Result<String, Stri
 #[tauri::command]
 pub async fn gateway_status(state: State<'_, GatewayState>) -> Result<GatewayStatus, String> {
     let running = crate::gateway::is_gateway_running(&state).await;
-    let logs = crate::gateway::get_gateway_logs(&state).await;
+    let logs = crate::logging::query_logs(None, None, Some(200), Some("gateway"))
+        .into_iter()
+        .map(|e| e.message)
+        .collect();
+    let auto_start_enabled = crate::config::read_config()
+        .map(|c| c.gateway.auto_start)
+        .unwrap_or(false);
 
     Ok(GatewayStatus {
         running,
         port: 18789,
         health: if running { "healthy".to_string() } else { "not running".to_string() },
         logs,
+        service_installed: crate::service_manager::service_installed(),
+        auto_start_enabled,
     })
 }
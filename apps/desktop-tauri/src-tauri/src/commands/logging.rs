@@ -0,0 +1,22 @@
+use crate::logging::LogEntry;
+
+#[tauri::command]
+pub fn query_logs(
+    level: Option<String>,
+    since: Option<String>,
+    limit: Option<usize>,
+    service: Option<String>,
+) -> Vec<LogEntry> {
+    crate::logging::query_logs(
+        level.as_deref(),
+        since.as_deref(),
+        limit,
+        service.as_deref(),
+    )
+}
+
+#[tauri::command]
+pub fn export_logs(path: String) -> Result<String, String> {
+    crate::logging::export_logs(&path)?;
+    Ok(format!("Logs exported to {}", path))
+}
@@ -0,0 +1,12 @@
+pub mod agents;
+pub mod bench;
+pub mod crash;
+pub mod docker;
+pub mod gateway_cmd;
+pub mod logging;
+pub mod models;
+pub mod onboarding;
+pub mod privacy;
+pub mod service_manager;
+pub mod system;
+pub mod workers;
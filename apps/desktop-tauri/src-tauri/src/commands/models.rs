@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::workers::{Tranquility, Worker, WorkerControl, WorkerRegistry};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ModelEntry {
@@ -38,9 +41,23 @@ pub async fn list_models() -> Result<Vec<ModelEntry>, String> {
         .collect())
 }
 
+/// Start a model download as a background worker and return its worker name immediately;
+/// progress is reported via `pull-progress` events and `workers::list_workers`.
+#[tauri::command]
+pub async fn pull_model(
+    app: AppHandle,
+    registry: State<'_, WorkerRegistry>,
+    name: String,
+) -> Result<String, String> {
+    let worker = crate::workers::OllamaPullWorker::new(app, name).await?;
+    let worker_name = worker.name();
+    crate::workers::spawn_worker(&registry, Box::new(worker), Tranquility::from_config()).await;
+    Ok(worker_name)
+}
+
 #[tauri::command]
-pub async fn pull_model(name: String) -> Result<String, String> {
-    crate::ollama::pull_model(&name).await
+pub async fn cancel_pull(registry: State<'_, WorkerRegistry>, name: String) -> Result<(), String> {
+    crate::workers::send_control(&registry, &format!("pull:{}", name), WorkerControl::Cancel).await
 }
 
 #[tauri::command]
@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::gateway::GatewayState;
+use crate::network::ConnectionEntry;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PrivacyScore {
@@ -8,6 +12,35 @@ pub struct PrivacyScore {
     pub redacted_messages: u64,
     pub total_messages: u64,
     pub recommendations: Vec<String>,
+    /// Live outbound-connection counts, merged in locally so the score reflects
+    /// actual network activity rather than just Gateway-reported message flow
+    #[serde(default)]
+    pub local_connections: u32,
+    #[serde(default)]
+    pub cloud_connections: u32,
+    #[serde(default)]
+    pub remote_hosts: Vec<String>,
+}
+
+/// Merge the live connection table into a privacy score and downgrade the score itself
+/// when Alfred's own processes have unexpected outbound (cloud) traffic
+async fn with_connection_summary(mut score: PrivacyScore, gateway_state: &GatewayState) -> PrivacyScore {
+    let summary = crate::network::current_summary(gateway_state).await;
+    score.local_connections = summary.local_connections;
+    score.cloud_connections = summary.cloud_connections;
+    score.remote_hosts = summary.remote_hosts;
+
+    if score.cloud_connections > 0 {
+        let penalty = (score.cloud_connections * 10).min(score.score);
+        score.score -= penalty;
+        score.recommendations.push(format!(
+            "{} outbound connection(s) detected from Alfred processes to {} host(s)",
+            score.cloud_connections,
+            score.remote_hosts.len()
+        ));
+    }
+
+    score
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -23,7 +56,7 @@ pub struct AuditLogEntry {
 
 /// Get privacy score from the Gateway API
 #[tauri::command]
-pub async fn get_privacy_score() -> Result<PrivacyScore, String> {
+pub async fn get_privacy_score(gateway_state: State<'_, GatewayState>) -> Result<PrivacyScore, String> {
     let client = reqwest::Client::new();
     let resp = client
         .get("http://127.0.0.1:18789/api/privacy/score")
@@ -32,9 +65,11 @@ pub async fn get_privacy_score() -> Result<PrivacyScore, String> {
 
     match resp {
         Ok(r) if r.status().is_success() => {
-            r.json::<PrivacyScore>()
+            let score = r
+                .json::<PrivacyScore>()
                 .await
-                .map_err(|e| format!("Failed to parse privacy score: {}", e))
+                .map_err(|e| format!("Failed to parse privacy score: {}", e))?;
+            Ok(with_connection_summary(score, &gateway_state).await)
         }
         Ok(r) => {
             // Gateway returned an error, provide default
@@ -42,20 +77,34 @@ pub async fn get_privacy_score() -> Result<PrivacyScore, String> {
         }
         Err(_) => {
             // Gateway not available, return default score
-            Ok(PrivacyScore {
-                score: 100,
-                local_messages: 0,
-                cloud_messages: 0,
-                redacted_messages: 0,
-                total_messages: 0,
-                recommendations: vec![
-                    "Gateway not connected - all data stays local by default".to_string(),
-                ],
-            })
+            Ok(with_connection_summary(
+                PrivacyScore {
+                    score: 100,
+                    local_messages: 0,
+                    cloud_messages: 0,
+                    redacted_messages: 0,
+                    total_messages: 0,
+                    recommendations: vec![
+                        "Gateway not connected - all data stays local by default".to_string(),
+                    ],
+                    local_connections: 0,
+                    cloud_connections: 0,
+                    remote_hosts: Vec::new(),
+                },
+                &gateway_state,
+            )
+            .await)
         }
     }
 }
 
+/// Get the current connection table: every TCP/UDP socket on the system, local and
+/// outbound, each with its scope and owning PID where the OS exposes one
+#[tauri::command]
+pub fn get_network_connections() -> Result<Vec<ConnectionEntry>, String> {
+    crate::network::list_connections()
+}
+
 /// Get audit log entries from the Gateway API
 #[tauri::command]
 pub async fn get_audit_log(limit: Option<u32>) -> Result<Vec<AuditLogEntry>, String> {
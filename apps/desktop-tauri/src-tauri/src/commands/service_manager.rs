@@ -0,0 +1,28 @@
+#[tauri::command]
+pub fn install_service() -> Result<String, String> {
+    crate::service_manager::install_service()?;
+    Ok("Gateway installed as a native service".to_string())
+}
+
+#[tauri::command]
+pub fn uninstall_service() -> Result<String, String> {
+    crate::service_manager::uninstall_service()?;
+    Ok("Gateway service uninstalled".to_string())
+}
+
+#[tauri::command]
+pub fn start_service() -> Result<String, String> {
+    crate::service_manager::start_service()?;
+    Ok("Gateway service started".to_string())
+}
+
+#[tauri::command]
+pub fn stop_service() -> Result<String, String> {
+    crate::service_manager::stop_service()?;
+    Ok("Gateway service stopped".to_string())
+}
+
+#[tauri::command]
+pub fn service_installed() -> bool {
+    crate::service_manager::service_installed()
+}
@@ -0,0 +1,23 @@
+use tauri::State;
+
+use crate::workers::{WorkerControl, WorkerRegistry, WorkerStatus};
+
+#[tauri::command]
+pub async fn list_workers(registry: State<'_, WorkerRegistry>) -> Result<Vec<WorkerStatus>, String> {
+    Ok(crate::workers::list_workers(&registry).await)
+}
+
+#[tauri::command]
+pub async fn pause_worker(registry: State<'_, WorkerRegistry>, name: String) -> Result<(), String> {
+    crate::workers::send_control(&registry, &name, WorkerControl::Pause).await
+}
+
+#[tauri::command]
+pub async fn resume_worker(registry: State<'_, WorkerRegistry>, name: String) -> Result<(), String> {
+    crate::workers::send_control(&registry, &name, WorkerControl::Resume).await
+}
+
+#[tauri::command]
+pub async fn cancel_worker(registry: State<'_, WorkerRegistry>, name: String) -> Result<(), String> {
+    crate::workers::send_control(&registry, &name, WorkerControl::Cancel).await
+}
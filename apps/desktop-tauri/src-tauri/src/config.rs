@@ -20,6 +20,12 @@ pub struct AlfredConfig {
 
     #[serde(default)]
     pub ui: UiConfig,
+
+    #[serde(default)]
+    pub logging: LoggingConfig,
+
+    #[serde(default)]
+    pub workers: WorkersConfig,
 }
 
 fn default_version() -> String {
@@ -46,6 +52,10 @@ pub struct ModelsConfig {
 
     #[serde(default)]
     pub ollama_host: String,
+
+    /// After a download completes, check that `/api/tags` lists the model with a recorded digest
+    #[serde(default)]
+    pub verify_digests: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -58,6 +68,13 @@ pub struct PrivacyConfig {
 
     #[serde(default = "default_true")]
     pub audit_enabled: bool,
+
+    /// Opt-in: upload crash reports to `crash_report_endpoint` when set
+    #[serde(default)]
+    pub crash_reporting_enabled: bool,
+
+    #[serde(default)]
+    pub crash_report_endpoint: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -101,6 +118,51 @@ fn default_theme() -> String {
     "dark".to_string()
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LoggingConfig {
+    #[serde(default = "default_log_level")]
+    pub level: String,
+
+    #[serde(default = "default_log_retention_days")]
+    pub retention_days: u32,
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_log_retention_days() -> u32 {
+    14
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: default_log_level(),
+            retention_days: default_log_retention_days(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorkersConfig {
+    /// How long a background worker sleeps between iterations while idle
+    #[serde(default = "default_tranquility_ms")]
+    pub tranquility_ms: u64,
+}
+
+fn default_tranquility_ms() -> u64 {
+    500
+}
+
+impl Default for WorkersConfig {
+    fn default() -> Self {
+        Self {
+            tranquility_ms: default_tranquility_ms(),
+        }
+    }
+}
+
 impl Default for AlfredConfig {
     fn default() -> Self {
         Self {
@@ -110,6 +172,8 @@ impl Default for AlfredConfig {
             privacy: PrivacyConfig::default(),
             channels: ChannelsConfig::default(),
             ui: UiConfig::default(),
+            logging: LoggingConfig::default(),
+            workers: WorkersConfig::default(),
         }
     }
 }
@@ -0,0 +1,156 @@
+use backtrace::Backtrace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrashReport {
+    pub app_version: String,
+    pub timestamp: String,
+    pub thread_name: String,
+    pub message: String,
+    pub frames: Vec<String>,
+    pub system: crate::hardware::SystemSnapshot,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrashReportSummary {
+    pub file: String,
+    pub timestamp: String,
+    pub message: String,
+}
+
+fn crashes_dir() -> std::path::PathBuf {
+    crate::config::get_alfred_home().join("crashes")
+}
+
+fn now_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+/// Demangle every frame of a captured backtrace into human-readable symbol names
+fn demangled_frames(backtrace: &Backtrace) -> Vec<String> {
+    let mut frames = Vec::new();
+    for frame in backtrace.frames() {
+        for symbol in frame.symbols() {
+            let raw = symbol
+                .name()
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| "<unknown>".to_string());
+            frames.push(rustc_demangle::demangle(&raw).to_string());
+        }
+    }
+    frames
+}
+
+/// Install a panic hook that captures the payload, thread name, and a demangled
+/// backtrace, then writes a crash report to ALFRED_HOME/crashes.
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let thread_name = std::thread::current()
+            .name()
+            .unwrap_or("unknown")
+            .to_string();
+
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+
+        let location = info
+            .location()
+            .map(|l| format!(" at {}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+
+        let backtrace = Backtrace::new();
+
+        let report = CrashReport {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            timestamp: now_timestamp(),
+            thread_name,
+            message: format!("{}{}", message, location),
+            frames: demangled_frames(&backtrace),
+            system: crate::hardware::get_system_snapshot(),
+        };
+
+        if let Err(e) = write_report(&report) {
+            eprintln!("[crash] Failed to write crash report: {}", e);
+        }
+    }));
+}
+
+fn write_report(report: &CrashReport) -> Result<(), String> {
+    let dir = crashes_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create crashes directory: {}", e))?;
+
+    let path = dir.join(format!("crash-{}.json", report.timestamp));
+    let content = serde_json::to_string_pretty(report)
+        .map_err(|e| format!("Failed to serialize crash report: {}", e))?;
+
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write crash report: {}", e))
+}
+
+/// List recent crash reports as lightweight summaries
+pub fn list_crash_reports() -> Result<Vec<CrashReportSummary>, String> {
+    let dir = crashes_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(&dir).map_err(|e| format!("Failed to read crashes directory: {}", e))?;
+
+    let mut summaries = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(report) = serde_json::from_str::<CrashReport>(&content) {
+                summaries.push(CrashReportSummary {
+                    file: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+                    timestamp: report.timestamp,
+                    message: report.message,
+                });
+            }
+        }
+    }
+
+    summaries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(summaries)
+}
+
+/// Upload a crash report to the configured endpoint, only when the user has opted in
+pub async fn upload_crash_report(file_name: &str) -> Result<String, String> {
+    let config = crate::config::read_config()?;
+
+    if !config.privacy.crash_reporting_enabled {
+        return Err("Crash reporting is not enabled in Privacy settings".to_string());
+    }
+
+    let endpoint = config
+        .privacy
+        .crash_report_endpoint
+        .ok_or_else(|| "No crash report endpoint configured".to_string())?;
+
+    let path = crashes_dir().join(file_name);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read crash report: {}", e))?;
+    let report: CrashReport = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse crash report: {}", e))?;
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(&endpoint)
+        .header("X-Report-Expiry-Hint", "7d")
+        .json(&report)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to upload crash report: {}", e))?;
+
+    if resp.status().is_success() {
+        Ok("Crash report uploaded".to_string())
+    } else {
+        Err(format!("Upload failed with status: {}", resp.status()))
+    }
+}
@@ -0,0 +1,80 @@
+use notify::{RecursiveMode, Watcher};
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use tauri::Emitter;
+
+const DEBOUNCE_MS: u64 = 500;
+
+/// Opt-in dev loop: watch the Gateway TypeScript source tree and hot-restart the
+/// child process on change, so contributors don't have to bounce the desktop app
+/// by hand while iterating on the Gateway.
+pub fn start(gateway_state: crate::gateway::GatewayState, app: tauri::AppHandle) {
+    if !enabled() {
+        return;
+    }
+
+    let watch_dir = crate::config::get_alfred_home().join("gateway").join("src");
+    if !watch_dir.exists() {
+        return;
+    }
+
+    std::thread::spawn(move || watch_loop(watch_dir, gateway_state, app));
+}
+
+fn enabled() -> bool {
+    cfg!(debug_assertions) || std::env::var("ALFRED_DEV_WATCH").is_ok()
+}
+
+fn is_ignored(path: &std::path::Path) -> bool {
+    let s = path.to_string_lossy();
+    s.contains("node_modules") || s.contains("/dist") || s.contains("\\dist")
+}
+
+fn is_relevant(event: &notify::Event) -> bool {
+    event.paths.iter().any(|p| !is_ignored(p))
+}
+
+fn watch_loop(watch_dir: std::path::PathBuf, gateway_state: crate::gateway::GatewayState, app: tauri::AppHandle) {
+    let (tx, rx) = channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("[dev-watch] Failed to create watcher: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = watcher.watch(&watch_dir, RecursiveMode::Recursive) {
+        eprintln!("[dev-watch] Failed to watch Gateway source: {}", e);
+        return;
+    }
+
+    loop {
+        let first = match rx.recv() {
+            Ok(Ok(event)) if is_relevant(&event) => event,
+            Ok(_) | Err(_) => continue,
+        };
+        let _ = first;
+
+        // Debounce: a burst of editor saves should trigger a single restart
+        loop {
+            match rx.recv_timeout(Duration::from_millis(DEBOUNCE_MS)) {
+                Ok(_) => continue,
+                Err(_) => break,
+            }
+        }
+
+        let gw_state = gateway_state.clone();
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let _ = crate::gateway::stop_gateway(&gw_state).await;
+            match crate::gateway::start_gateway(&gw_state, &app_handle).await {
+                Ok(_) => {
+                    println!("[dev-watch] Gateway reloaded");
+                    let _ = app_handle.emit("gateway-reloaded", ());
+                }
+                Err(e) => eprintln!("[dev-watch] Failed to restart Gateway: {}", e),
+            }
+        });
+    }
+}
@@ -1,5 +1,11 @@
+use futures_util::StreamExt;
+use hyper::body::Buf;
+use hyper::{Body, Client, Method, Request};
+use hyperlocal::{UnixClientExt, UnixConnector, Uri as UnixUri};
 use serde::{Deserialize, Serialize};
-use std::process::Command;
+
+#[cfg(unix)]
+const DOCKER_SOCKET: &str = "/var/run/docker.sock";
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ContainerInfo {
@@ -11,127 +17,432 @@ pub struct ContainerInfo {
     pub running: bool,
 }
 
-/// Check if Docker is available and running
-pub fn is_docker_available() -> bool {
-    Command::new("docker")
-        .arg("info")
-        .output()
-        .map(|o| o.status.success())
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContainerSpec {
+    pub name: String,
+    pub image: String,
+    /// host:container port mappings, e.g. "8888:8080"
+    pub ports: Vec<String>,
+    pub env: Vec<String>,
+    /// host:container volume mappings
+    pub volumes: Vec<String>,
+    pub restart_policy: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContainerDetail {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub running: bool,
+    pub status: String,
+    pub health: Option<String>,
+    pub ip_address: Option<String>,
+    /// PID of the container's main process on the host, for correlating with network sockets
+    pub pid: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContainerStats {
+    pub cpu_percent: f64,
+    pub memory_usage_mb: f64,
+    pub memory_limit_mb: f64,
+    pub network_rx_bytes: u64,
+    pub network_tx_bytes: u64,
+}
+
+#[cfg(unix)]
+fn client() -> Client<UnixConnector> {
+    Client::unix()
+}
+
+#[cfg(unix)]
+fn uri(path: &str) -> hyper::Uri {
+    UnixUri::new(DOCKER_SOCKET, path).into()
+}
+
+#[cfg(not(unix))]
+fn not_supported<T>() -> Result<T, String> {
+    // The Docker Engine API client only speaks Unix sockets today; Windows talks
+    // to the daemon over the `npipe:////./pipe/docker_engine` named pipe instead.
+    Err("Docker Engine API access is not yet implemented on this platform".to_string())
+}
+
+/// GET a path from the Docker Engine API and parse the JSON body
+#[cfg(unix)]
+async fn engine_get(path: &str) -> Result<serde_json::Value, String> {
+    let resp = client()
+        .get(uri(path))
+        .await
+        .map_err(|e| format!("Failed to reach Docker Engine API: {}", e))?;
+
+    let status = resp.status();
+    let body = hyper::body::aggregate(resp)
+        .await
+        .map_err(|e| format!("Failed to read Docker Engine API response: {}", e))?;
+
+    if !status.is_success() {
+        return Err(format!("Docker Engine API returned status: {}", status));
+    }
+
+    serde_json::from_reader(body.reader())
+        .map_err(|e| format!("Failed to parse Docker Engine API response: {}", e))
+}
+
+/// POST a path with an optional JSON body, returning the raw response body
+#[cfg(unix)]
+async fn engine_post(path: &str, body: Option<serde_json::Value>) -> Result<String, String> {
+    let req_body = match &body {
+        Some(b) => Body::from(serde_json::to_vec(b).map_err(|e| format!("Failed to encode request: {}", e))?),
+        None => Body::empty(),
+    };
+
+    let mut builder = Request::builder().method(Method::POST).uri(uri(path));
+    if body.is_some() {
+        builder = builder.header("Content-Type", "application/json");
+    }
+    let req = builder
+        .body(req_body)
+        .map_err(|e| format!("Failed to build request: {}", e))?;
+
+    let resp = client()
+        .request(req)
+        .await
+        .map_err(|e| format!("Failed to reach Docker Engine API: {}", e))?;
+
+    let status = resp.status();
+    let bytes = hyper::body::to_bytes(resp.into_body())
+        .await
+        .map_err(|e| format!("Failed to read Docker Engine API response: {}", e))?;
+    let text = String::from_utf8_lossy(&bytes).to_string();
+
+    if !status.is_success() && status.as_u16() != 304 {
+        return Err(format!("Docker Engine API returned status {}: {}", status, text));
+    }
+
+    Ok(text)
+}
+
+/// Check if the Docker daemon is reachable over its local socket
+#[cfg(unix)]
+pub async fn is_docker_available() -> bool {
+    // /_ping replies with a plain-text "OK" body, not JSON, so this only checks status
+    client()
+        .get(uri("/_ping"))
+        .await
+        .map(|r| r.status().is_success())
         .unwrap_or(false)
 }
 
-/// Start a SearXNG container with proper configuration
-pub fn start_searxng() -> Result<String, String> {
-    let output = Command::new("docker")
-        .args([
-            "run",
-            "-d",
-            "--name", "alfred-searxng",
-            "-p", "8888:8080",
-            "-e", "SEARXNG_BASE_URL=http://localhost:8888",
-            "--restart", "unless-stopped",
-            "searxng/searxng:latest",
-        ])
-        .output()
-        .map_err(|e| format!("Failed to start SearXNG: {}", e))?;
-
-    if output.status.success() {
-        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        Ok(format!("SearXNG started with container ID: {}", id))
-    } else {
-        let err = String::from_utf8_lossy(&output.stderr);
-        Err(format!("Failed to start SearXNG: {}", err))
+#[cfg(not(unix))]
+pub async fn is_docker_available() -> bool {
+    false
+}
+
+/// List containers, optionally filtered by name prefix. Pass `None` to list all Alfred containers.
+#[cfg(unix)]
+pub async fn list_containers(name_filter: Option<&str>) -> Result<Vec<ContainerInfo>, String> {
+    let filter = name_filter.unwrap_or("alfred-");
+    let filters = serde_json::json!({ "name": [filter] }).to_string();
+    let path = format!("/containers/json?all=true&filters={}", urlencode(&filters));
+
+    let value = engine_get(&path).await?;
+    Ok(value
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ContainerInfo {
+            id: c["Id"].as_str().unwrap_or_default().to_string(),
+            name: c["Names"]
+                .as_array()
+                .and_then(|n| n.first())
+                .and_then(|n| n.as_str())
+                .map(|n| n.trim_start_matches('/').to_string())
+                .unwrap_or_default(),
+            image: c["Image"].as_str().unwrap_or_default().to_string(),
+            status: c["Status"].as_str().unwrap_or_default().to_string(),
+            ports: c["Ports"].to_string(),
+            running: c["State"].as_str() == Some("running"),
+        })
+        .collect())
+}
+
+#[cfg(not(unix))]
+pub async fn list_containers(_name_filter: Option<&str>) -> Result<Vec<ContainerInfo>, String> {
+    not_supported()
+}
+
+/// Create and start a container from a spec if it doesn't already exist, otherwise start it
+#[cfg(unix)]
+pub async fn start_container(spec: &ContainerSpec) -> Result<String, String> {
+    if inspect_container(&spec.name).await.is_ok() {
+        engine_post(&format!("/containers/{}/start", spec.name), None).await?;
+        return Ok(format!("Started existing container: {}", spec.name));
     }
+
+    let port_bindings: serde_json::Value = spec
+        .ports
+        .iter()
+        .filter_map(|mapping| {
+            let mut parts = mapping.splitn(2, ':');
+            let host = parts.next()?;
+            let container = parts.next()?;
+            Some((
+                format!("{}/tcp", container),
+                serde_json::json!([{ "HostPort": host }]),
+            ))
+        })
+        .collect();
+
+    let volume_binds: Vec<String> = spec.volumes.clone();
+
+    let create_body = serde_json::json!({
+        "Image": spec.image,
+        "Env": spec.env,
+        "HostConfig": {
+            "PortBindings": port_bindings,
+            "Binds": volume_binds,
+            "RestartPolicy": { "Name": spec.restart_policy },
+        },
+    });
+
+    let create_resp = engine_post(
+        &format!("/containers/create?name={}", spec.name),
+        Some(create_body),
+    )
+    .await?;
+
+    let created: serde_json::Value =
+        serde_json::from_str(&create_resp).map_err(|e| format!("Failed to parse create response: {}", e))?;
+    let id = created["Id"].as_str().unwrap_or_default().to_string();
+
+    engine_post(&format!("/containers/{}/start", id), None).await?;
+
+    Ok(format!("Container {} started with ID: {}", spec.name, id))
 }
 
-/// Stop the SearXNG container
-pub fn stop_searxng() -> Result<String, String> {
-    let output = Command::new("docker")
-        .args(["stop", "alfred-searxng"])
-        .output()
-        .map_err(|e| format!("Failed to stop SearXNG: {}", e))?;
-
-    if output.status.success() {
-        // Also remove the container
-        let _ = Command::new("docker").args(["rm", "alfred-searxng"]).output();
-        Ok("SearXNG stopped".to_string())
+#[cfg(not(unix))]
+pub async fn start_container(_spec: &ContainerSpec) -> Result<String, String> {
+    not_supported()
+}
+
+/// Stop a container by name
+#[cfg(unix)]
+pub async fn stop_container(name: &str) -> Result<String, String> {
+    engine_post(&format!("/containers/{}/stop", name), None).await?;
+    Ok(format!("Container {} stopped", name))
+}
+
+#[cfg(not(unix))]
+pub async fn stop_container(_name: &str) -> Result<String, String> {
+    not_supported()
+}
+
+/// Restart a container by name
+#[cfg(unix)]
+pub async fn restart_container(name: &str) -> Result<String, String> {
+    engine_post(&format!("/containers/{}/restart", name), None).await?;
+    Ok(format!("Container {} restarted", name))
+}
+
+#[cfg(not(unix))]
+pub async fn restart_container(_name: &str) -> Result<String, String> {
+    not_supported()
+}
+
+/// Inspect a container and return its id, image, running state, and health
+#[cfg(unix)]
+pub async fn inspect_container(name: &str) -> Result<ContainerDetail, String> {
+    let entry = engine_get(&format!("/containers/{}/json", name)).await?;
+
+    Ok(ContainerDetail {
+        id: entry["Id"].as_str().unwrap_or_default().to_string(),
+        name: name.to_string(),
+        image: entry["Config"]["Image"].as_str().unwrap_or_default().to_string(),
+        running: entry["State"]["Running"].as_bool().unwrap_or(false),
+        status: entry["State"]["Status"].as_str().unwrap_or_default().to_string(),
+        health: entry["State"]["Health"]["Status"].as_str().map(|s| s.to_string()),
+        ip_address: entry["NetworkSettings"]["IPAddress"]
+            .as_str()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string()),
+        pid: entry["State"]["Pid"].as_u64().filter(|&p| p != 0).map(|p| p as u32),
+    })
+}
+
+#[cfg(not(unix))]
+pub async fn inspect_container(_name: &str) -> Result<ContainerDetail, String> {
+    not_supported()
+}
+
+/// Fetch the recent logs for a container
+#[cfg(unix)]
+pub async fn container_logs(name: &str, tail: Option<u32>) -> Result<String, String> {
+    let tail = tail.unwrap_or(200);
+    let path = format!(
+        "/containers/{}/logs?stdout=true&stderr=true&tail={}",
+        name, tail
+    );
+
+    let resp = client()
+        .get(uri(&path))
+        .await
+        .map_err(|e| format!("Failed to fetch logs for container: {}", e))?;
+    let bytes = hyper::body::to_bytes(resp.into_body())
+        .await
+        .map_err(|e| format!("Failed to read container logs: {}", e))?;
+
+    // Strip the 8-byte multiplexed stream-frame headers Docker prefixes each log chunk with
+    Ok(strip_stream_headers(&bytes))
+}
+
+#[cfg(not(unix))]
+pub async fn container_logs(_name: &str, _tail: Option<u32>) -> Result<String, String> {
+    not_supported()
+}
+
+fn strip_stream_headers(bytes: &[u8]) -> String {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i + 8 <= bytes.len() {
+        let len = u32::from_be_bytes([bytes[i + 4], bytes[i + 5], bytes[i + 6], bytes[i + 7]]) as usize;
+        let start = i + 8;
+        let end = (start + len).min(bytes.len());
+        out.extend_from_slice(&bytes[start..end]);
+        i = end;
+    }
+    if out.is_empty() {
+        String::from_utf8_lossy(bytes).to_string()
     } else {
-        let err = String::from_utf8_lossy(&output.stderr);
-        Err(format!("Failed to stop SearXNG: {}", err))
+        String::from_utf8_lossy(&out).to_string()
     }
 }
 
-/// Check if the SearXNG container is running
-pub fn is_searxng_running() -> bool {
-    let output = Command::new("docker")
-        .args(["ps", "--filter", "name=alfred-searxng", "--format", "{{.Status}}"])
-        .output();
+/// Stream CPU/memory/network samples for a running container, emitting one
+/// `(container name, ContainerStats)` pair per update via the Tauri event named `docker-stats`
+#[cfg(unix)]
+pub async fn stream_stats(
+    app: &tauri::AppHandle,
+    name: &str,
+) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let resp = client()
+        .get(uri(&format!("/containers/{}/stats?stream=true", name)))
+        .await
+        .map_err(|e| format!("Failed to reach Docker Engine API: {}", e))?;
+
+    let mut body = resp.into_body();
+    let mut buf = Vec::new();
 
-    match output {
-        Ok(o) => {
-            let status = String::from_utf8_lossy(&o.stdout);
-            status.trim().contains("Up")
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|e| format!("Error reading stats stream: {}", e))?;
+        buf.extend_from_slice(&chunk);
+
+        while let Some(pos) = buf.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = buf.drain(..=pos).collect();
+            if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&line) {
+                if let Some(stats) = parse_stats(&value) {
+                    let _ = app.emit("docker-stats", (name.to_string(), stats));
+                }
+            }
         }
-        Err(_) => false,
     }
+
+    Ok(())
 }
 
-/// Start a signal-cli-rest container
-pub fn start_signal_cli() -> Result<String, String> {
-    let data_dir = crate::config::get_alfred_home().join("signal-cli");
-    std::fs::create_dir_all(&data_dir).ok();
+#[cfg(not(unix))]
+pub async fn stream_stats(_app: &tauri::AppHandle, _name: &str) -> Result<(), String> {
+    not_supported()
+}
 
-    let output = Command::new("docker")
-        .args([
-            "run",
-            "-d",
-            "--name", "alfred-signal-cli",
-            "-p", "8820:8080",
-            "-v", &format!("{}:/home/.local/share/signal-cli", data_dir.to_string_lossy()),
-            "--restart", "unless-stopped",
-            "bbernhard/signal-cli-rest-api:latest",
-        ])
-        .output()
-        .map_err(|e| format!("Failed to start signal-cli: {}", e))?;
-
-    if output.status.success() {
-        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        Ok(format!("signal-cli started with container ID: {}", id))
+fn parse_stats(value: &serde_json::Value) -> Option<ContainerStats> {
+    let cpu_delta = value["cpu_stats"]["cpu_usage"]["total_usage"].as_f64()?
+        - value["precpu_stats"]["cpu_usage"]["total_usage"].as_f64().unwrap_or(0.0);
+    let system_delta = value["cpu_stats"]["system_cpu_usage"].as_f64()?
+        - value["precpu_stats"]["system_cpu_usage"].as_f64().unwrap_or(0.0);
+    let online_cpus = value["cpu_stats"]["online_cpus"].as_f64().unwrap_or(1.0);
+
+    let cpu_percent = if system_delta > 0.0 && cpu_delta > 0.0 {
+        (cpu_delta / system_delta) * online_cpus * 100.0
     } else {
-        let err = String::from_utf8_lossy(&output.stderr);
-        Err(format!("Failed to start signal-cli: {}", err))
+        0.0
+    };
+
+    let memory_usage = value["memory_stats"]["usage"].as_f64().unwrap_or(0.0);
+    let memory_limit = value["memory_stats"]["limit"].as_f64().unwrap_or(0.0);
+
+    let mut rx = 0u64;
+    let mut tx = 0u64;
+    if let Some(networks) = value["networks"].as_object() {
+        for (_, net) in networks {
+            rx += net["rx_bytes"].as_u64().unwrap_or(0);
+            tx += net["tx_bytes"].as_u64().unwrap_or(0);
+        }
     }
+
+    Some(ContainerStats {
+        cpu_percent,
+        memory_usage_mb: memory_usage / 1_048_576.0,
+        memory_limit_mb: memory_limit / 1_048_576.0,
+        network_rx_bytes: rx,
+        network_tx_bytes: tx,
+    })
 }
 
-/// List all Alfred-related Docker containers
-pub fn list_containers() -> Result<Vec<ContainerInfo>, String> {
-    let output = Command::new("docker")
-        .args([
-            "ps",
-            "-a",
-            "--filter", "name=alfred-",
-            "--format", "{{.ID}}|{{.Names}}|{{.Image}}|{{.Status}}|{{.Ports}}",
-        ])
-        .output()
-        .map_err(|e| format!("Failed to list containers: {}", e))?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let containers: Vec<ContainerInfo> = stdout
-        .trim()
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let parts: Vec<&str> = line.split('|').collect();
-            ContainerInfo {
-                id: parts.first().unwrap_or(&"").to_string(),
-                name: parts.get(1).unwrap_or(&"").to_string(),
-                image: parts.get(2).unwrap_or(&"").to_string(),
-                status: parts.get(3).unwrap_or(&"").to_string(),
-                ports: parts.get(4).unwrap_or(&"").to_string(),
-                running: parts.get(3).unwrap_or(&"").contains("Up"),
-            }
+fn urlencode(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+            _ => format!("%{:02X}", c as u32),
         })
-        .collect();
+        .collect()
+}
+
+fn searxng_spec() -> ContainerSpec {
+    ContainerSpec {
+        name: "alfred-searxng".to_string(),
+        image: "searxng/searxng:latest".to_string(),
+        ports: vec!["8888:8080".to_string()],
+        env: vec!["SEARXNG_BASE_URL=http://localhost:8888".to_string()],
+        volumes: Vec::new(),
+        restart_policy: "unless-stopped".to_string(),
+    }
+}
+
+/// Start the SearXNG container, creating it with the right port mapping if it doesn't exist
+pub async fn start_searxng() -> Result<String, String> {
+    start_container(&searxng_spec()).await
+}
+
+/// Stop the SearXNG container
+pub async fn stop_searxng() -> Result<String, String> {
+    stop_container("alfred-searxng").await
+}
+
+/// Check if the SearXNG container is running, using real container state rather than
+/// substring-matching a status string
+pub async fn is_searxng_running() -> bool {
+    inspect_container("alfred-searxng")
+        .await
+        .map(|c| c.running)
+        .unwrap_or(false)
+}
+
+/// Start a signal-cli-rest container
+pub async fn start_signal_cli() -> Result<String, String> {
+    let data_dir = crate::config::get_alfred_home().join("signal-cli");
+    std::fs::create_dir_all(&data_dir).ok();
 
-    Ok(containers)
+    start_container(&ContainerSpec {
+        name: "alfred-signal-cli".to_string(),
+        image: "bbernhard/signal-cli-rest-api:latest".to_string(),
+        ports: vec!["8820:8080".to_string()],
+        env: Vec::new(),
+        volumes: vec![format!("{}:/home/.local/share/signal-cli", data_dir.to_string_lossy())],
+        restart_policy: "unless-stopped".to_string(),
+    })
+    .await
 }
@@ -1,31 +1,89 @@
+use serde::Serialize;
+use std::collections::VecDeque;
 use std::process::Stdio;
 use std::sync::Arc;
+use tauri::Emitter;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
 use tokio::sync::Mutex;
 
+const LOG_BUFFER_CAP: usize = 2000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GatewayLogLine {
+    pub source: String,
+    pub line: String,
+    pub timestamp: String,
+}
+
 pub struct GatewayProcess {
-    child: Option<Child>,
-    logs: Vec<String>,
+    child: Option<Arc<Mutex<Child>>>,
+    logs: VecDeque<GatewayLogLine>,
 }
 
 impl GatewayProcess {
     pub fn new() -> Self {
         Self {
             child: None,
-            logs: Vec::new(),
+            logs: VecDeque::new(),
+        }
+    }
+
+    fn push_log(&mut self, source: &str, line: String) {
+        if self.logs.len() >= LOG_BUFFER_CAP {
+            self.logs.pop_front();
         }
+        self.logs.push_back(GatewayLogLine {
+            source: source.to_string(),
+            line,
+            timestamp: now_timestamp(),
+        });
     }
 }
 
+fn now_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
 pub type GatewayState = Arc<Mutex<GatewayProcess>>;
 
 pub fn create_gateway_state() -> GatewayState {
     Arc::new(Mutex::new(GatewayProcess::new()))
 }
 
+/// Read lines from a child's stdout/stderr pipe into the bounded ring buffer,
+/// emitting each line to the frontend as it arrives. Exits cleanly once the
+/// pipe closes (on process exit or `stop_gateway` killing the child).
+fn spawn_log_reader<R>(state: GatewayState, app: tauri::AppHandle, source: &'static str, pipe: R)
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(pipe).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            crate::logging::log_service_line("gateway", &line);
+
+            let mut gw = state.lock().await;
+            gw.push_log(source, line.clone());
+            drop(gw);
+
+            let _ = app.emit(
+                "gateway-log",
+                GatewayLogLine {
+                    source: source.to_string(),
+                    line,
+                    timestamp: now_timestamp(),
+                },
+            );
+        }
+    });
+}
+
 /// Start the Gateway as a child process using npx tsx
-pub async fn start_gateway(state: &GatewayState) -> Result<(), String> {
+pub async fn start_gateway(state: &GatewayState, app: &tauri::AppHandle) -> Result<(), String> {
     let mut gw = state.lock().await;
 
     if gw.child.is_some() {
@@ -51,9 +109,23 @@ pub async fn start_gateway(state: &GatewayState) -> Result<(), String> {
     }
 
     match cmd.spawn() {
-        Ok(child) => {
-            gw.child = Some(child);
-            gw.logs.push("[gateway] Started successfully".to_string());
+        Ok(mut child) => {
+            let stdout = child.stdout.take();
+            let stderr = child.stderr.take();
+
+            gw.push_log("system", "Started successfully".to_string());
+            crate::logging::log_service_line("gateway", "Started successfully");
+
+            gw.child = Some(Arc::new(Mutex::new(child)));
+            drop(gw);
+
+            if let Some(stdout) = stdout {
+                spawn_log_reader(state.clone(), app.clone(), "stdout", stdout);
+            }
+            if let Some(stderr) = stderr {
+                spawn_log_reader(state.clone(), app.clone(), "stderr", stderr);
+            }
+
             Ok(())
         }
         Err(e) => Err(format!("Failed to start Gateway: {}", e)),
@@ -64,33 +136,74 @@ pub async fn start_gateway(state: &GatewayState) -> Result<(), String> {
 pub async fn stop_gateway(state: &GatewayState) -> Result<(), String> {
     let mut gw = state.lock().await;
 
-    if let Some(ref mut child) = gw.child {
-        child.kill().await.map_err(|e| format!("Failed to kill Gateway: {}", e))?;
-        gw.child = None;
-        gw.logs.push("[gateway] Stopped".to_string());
+    if let Some(child) = gw.child.take() {
+        child
+            .lock()
+            .await
+            .kill()
+            .await
+            .map_err(|e| format!("Failed to kill Gateway: {}", e))?;
+        gw.push_log("system", "Stopped".to_string());
+        crate::logging::log_service_line("gateway", "Stopped");
         Ok(())
     } else {
         Err("Gateway is not running".into())
     }
 }
 
+/// PIDs of the Gateway's whole process tree, for correlating with network sockets. The
+/// spawned child is `npx`, which execs/forks into the actual `node` process running
+/// `tsx` — the real HTTP listener and outbound sockets belong to that descendant, not
+/// `npx` itself, so descendants have to be walked rather than just returning the child id.
+pub async fn gateway_pids(state: &GatewayState) -> Vec<u32> {
+    let process = state.lock().await;
+    let Some(child) = process.child.as_ref() else {
+        return Vec::new();
+    };
+    let Some(root_pid) = child.lock().await.id() else {
+        return Vec::new();
+    };
+    drop(process);
+
+    use sysinfo::{Pid, System};
+    let sys = System::new_all();
+
+    let root = Pid::from_u32(root_pid);
+    let mut pids = vec![root_pid];
+    let mut frontier = vec![root];
+    while let Some(parent) = frontier.pop() {
+        for (pid, proc_) in sys.processes() {
+            if proc_.parent() == Some(parent) {
+                pids.push(pid.as_u32());
+                frontier.push(*pid);
+            }
+        }
+    }
+    pids
+}
+
 /// Check if the Gateway process is alive and health endpoint responds
 pub async fn is_gateway_running(state: &GatewayState) -> bool {
-    let gw = state.lock().await;
+    let has_child = {
+        let gw = state.lock().await;
+        gw.child.is_some()
+    };
 
-    if gw.child.is_none() {
+    if !has_child {
         return false;
     }
 
-    // Also check the health endpoint
+    // Check the health endpoint with the lock released — `spawn_log_reader` locks the
+    // same state per log line, and an unbounded reqwest::get held under that lock can
+    // stall log draining and back up the child's stdout/stderr pipe.
     match reqwest::get("http://127.0.0.1:18789/health").await {
         Ok(resp) => resp.status().is_success(),
         Err(_) => false,
     }
 }
 
-/// Get recent log output from the Gateway
-pub async fn get_gateway_logs(state: &GatewayState) -> Vec<String> {
+/// Get recent log output from the Gateway's in-memory ring buffer
+pub async fn get_gateway_logs(state: &GatewayState) -> Vec<GatewayLogLine> {
     let gw = state.lock().await;
-    gw.logs.clone()
+    gw.logs.iter().cloned().collect()
 }
@@ -7,6 +7,7 @@ pub struct GpuInfo {
     pub name: String,
     pub vram_mb: u64,
     pub driver_version: String,
+    pub vendor: String,
     pub detected: bool,
 }
 
@@ -36,7 +37,7 @@ pub struct DiskInfo {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SystemSnapshot {
-    pub gpu: GpuInfo,
+    pub gpu: Vec<GpuInfo>,
     pub cpu: CpuInfo,
     pub memory: MemoryInfo,
     pub disk: DiskInfo,
@@ -44,37 +45,188 @@ pub struct SystemSnapshot {
     pub hostname: String,
 }
 
-/// Detect NVIDIA GPU by parsing nvidia-smi output
-pub fn detect_gpu() -> GpuInfo {
+/// Detect every GPU we know how to probe for, trying each vendor's own tooling in turn.
+/// Vendors whose tool isn't installed (or isn't relevant on this platform) are silently
+/// skipped rather than treated as an error.
+pub fn detect_gpus() -> Vec<GpuInfo> {
+    let mut gpus = Vec::new();
+    gpus.extend(detect_nvidia_gpus());
+    gpus.extend(detect_amd_gpus());
+    gpus.extend(detect_intel_gpus());
+    gpus.extend(detect_apple_gpus());
+
+    if gpus.is_empty() {
+        gpus.push(no_gpu());
+    }
+
+    gpus
+}
+
+/// Detect NVIDIA GPUs by parsing nvidia-smi output (one CSV row per GPU)
+fn detect_nvidia_gpus() -> Vec<GpuInfo> {
     let output = Command::new("nvidia-smi")
         .args(["--query-gpu=name,memory.total,driver_version", "--format=csv,noheader,nounits"])
         .output();
 
-    match output {
-        Ok(out) => {
-            let stdout = String::from_utf8_lossy(&out.stdout);
-            let parts: Vec<&str> = stdout.trim().split(", ").collect();
-
-            if parts.len() >= 3 {
-                GpuInfo {
-                    name: parts[0].trim().to_string(),
-                    vram_mb: parts[1].trim().parse().unwrap_or(0),
-                    driver_version: parts[2].trim().to_string(),
-                    detected: true,
-                }
-            } else {
-                no_gpu()
+    let Ok(out) = output else { return Vec::new() };
+    if !out.status.success() {
+        return Vec::new();
+    }
+
+    String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split(", ").collect();
+            if parts.len() < 3 {
+                return None;
             }
+            Some(GpuInfo {
+                name: parts[0].trim().to_string(),
+                vram_mb: parts[1].trim().parse().unwrap_or(0),
+                driver_version: parts[2].trim().to_string(),
+                vendor: "nvidia".to_string(),
+                detected: true,
+            })
+        })
+        .collect()
+}
+
+/// Detect AMD GPUs via rocm-smi's JSON output
+fn detect_amd_gpus() -> Vec<GpuInfo> {
+    let output = Command::new("rocm-smi")
+        .args(["--showproductname", "--showmeminfo", "vram", "--showdriverversion", "--json"])
+        .output();
+
+    let Ok(out) = output else { return Vec::new() };
+    if !out.status.success() {
+        return Vec::new();
+    }
+
+    parse_rocm_smi_json(&String::from_utf8_lossy(&out.stdout))
+}
+
+fn parse_rocm_smi_json(json: &str) -> Vec<GpuInfo> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else { return Vec::new() };
+    let Some(cards) = value.as_object() else { return Vec::new() };
+
+    cards
+        .values()
+        .filter_map(|card| {
+            let name = card.get("Card series").and_then(|v| v.as_str())?.to_string();
+            let vram_bytes: u64 = card
+                .get("VRAM Total Memory (B)")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+            let driver_version = card
+                .get("Driver version")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string();
+
+            Some(GpuInfo {
+                name,
+                vram_mb: vram_bytes / 1_048_576,
+                driver_version,
+                vendor: "amd".to_string(),
+                detected: true,
+            })
+        })
+        .collect()
+}
+
+/// Detect an Intel integrated GPU via sysfs. Intel's integrated adapters don't have
+/// dedicated VRAM (they share system memory), so vram_mb is left at 0.
+fn detect_intel_gpus() -> Vec<GpuInfo> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else { return Vec::new() };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with("card") || name.contains('-') {
+            continue;
         }
-        Err(_) => no_gpu(),
+
+        let vendor = std::fs::read_to_string(entry.path().join("device/vendor")).unwrap_or_default();
+        if vendor.trim() != "0x8086" {
+            continue;
+        }
+
+        let device_name = std::fs::read_to_string(entry.path().join("device/label"))
+            .unwrap_or_else(|_| "Intel Graphics".to_string());
+
+        return vec![GpuInfo {
+            name: device_name.trim().to_string(),
+            vram_mb: 0,
+            driver_version: "unknown".to_string(),
+            vendor: "intel".to_string(),
+            detected: true,
+        }];
+    }
+
+    Vec::new()
+}
+
+/// Detect Apple Silicon GPUs via system_profiler. Unified memory is reported as VRAM
+/// since that's the budget that actually constrains how large a model can run.
+fn detect_apple_gpus() -> Vec<GpuInfo> {
+    let output = Command::new("system_profiler")
+        .args(["SPDisplaysDataType", "-json"])
+        .output();
+
+    let Ok(out) = output else { return Vec::new() };
+    if !out.status.success() {
+        return Vec::new();
+    }
+
+    parse_system_profiler(&String::from_utf8_lossy(&out.stdout))
+}
+
+fn parse_system_profiler(json: &str) -> Vec<GpuInfo> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else { return Vec::new() };
+    let Some(displays) = value.get("SPDisplaysDataType").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    displays
+        .iter()
+        .filter_map(|gpu| {
+            let name = gpu.get("sppci_model").and_then(|v| v.as_str())?.to_string();
+            let vram_mb = gpu
+                .get("spdisplays_vram_shared")
+                .or_else(|| gpu.get("spdisplays_vram"))
+                .and_then(|v| v.as_str())
+                .and_then(parse_mem_string)
+                .unwrap_or(0);
+
+            Some(GpuInfo {
+                name,
+                vram_mb,
+                driver_version: "unknown".to_string(),
+                vendor: "apple".to_string(),
+                detected: true,
+            })
+        })
+        .collect()
+}
+
+/// Parse strings like "16 GB" or "1536 MB" into megabytes
+fn parse_mem_string(s: &str) -> Option<u64> {
+    let (num, unit) = s.trim().split_once(' ')?;
+    let num: f64 = num.parse().ok()?;
+    match unit.to_ascii_uppercase().as_str() {
+        "GB" => Some((num * 1024.0) as u64),
+        "MB" => Some(num as u64),
+        _ => None,
     }
 }
 
 fn no_gpu() -> GpuInfo {
     GpuInfo {
-        name: "No NVIDIA GPU detected".to_string(),
+        name: "No GPU detected".to_string(),
         vram_mb: 0,
         driver_version: "N/A".to_string(),
+        vendor: "none".to_string(),
         detected: false,
     }
 }
@@ -164,7 +316,7 @@ pub fn get_system_snapshot() -> SystemSnapshot {
     );
 
     SystemSnapshot {
-        gpu: detect_gpu(),
+        gpu: detect_gpus(),
         cpu: get_cpu_info(),
         memory: get_memory_info(),
         disk: get_disk_info(),
@@ -1,24 +1,40 @@
+pub mod bench;
+pub mod cache;
 pub mod commands;
 pub mod config;
+pub mod crash;
+pub mod dev_watch;
 pub mod docker;
 pub mod gateway;
 pub mod hardware;
+pub mod logging;
+pub mod network;
 pub mod ollama;
+#[cfg(feature = "lua-services")]
+pub mod scripting;
+pub mod service_manager;
 pub mod services;
 pub mod tray;
 pub mod updater;
+pub mod workers;
 
 use tauri::{Emitter, Manager};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let config = config::read_config().unwrap_or_default();
+    logging::init(&config.logging);
+    crash::install_panic_hook();
+
     let gateway_state = gateway::create_gateway_state();
+    let worker_registry = workers::create_registry();
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(gateway_state.clone())
+        .manage(worker_registry)
         .setup(move |app| {
             let handle = app.handle().clone();
 
@@ -35,11 +51,14 @@ pub fn run() {
                 }
             }
 
+            // Hot-restart the Gateway on source changes in development
+            dev_watch::start(gateway_state.clone(), handle.clone());
+
             // Auto-start services in background
             let gw_state = gateway_state.clone();
             let app_handle = handle.clone();
             tauri::async_runtime::spawn(async move {
-                let statuses = services::auto_start(&gw_state).await;
+                let statuses = services::auto_start(&gw_state, &app_handle).await;
                 for status in &statuses {
                     println!(
                         "[startup] {} - {}",
@@ -76,9 +95,11 @@ pub fn run() {
             // Privacy commands
             commands::privacy::get_privacy_score,
             commands::privacy::get_audit_log,
+            commands::privacy::get_network_connections,
             // Model commands
             commands::models::list_models,
             commands::models::pull_model,
+            commands::models::cancel_pull,
             commands::models::delete_model,
             // Agent commands
             commands::agents::list_agents,
@@ -88,6 +109,36 @@ pub fn run() {
             // System commands
             commands::system::get_resources,
             commands::system::get_services_status,
+            // Benchmark commands
+            commands::bench::run_benchmark,
+            commands::bench::compare_benchmarks,
+            // Service manager commands
+            commands::service_manager::install_service,
+            commands::service_manager::uninstall_service,
+            commands::service_manager::start_service,
+            commands::service_manager::stop_service,
+            commands::service_manager::service_installed,
+            // Docker container commands
+            commands::docker::list_containers,
+            commands::docker::start_container,
+            commands::docker::stop_container,
+            commands::docker::restart_container,
+            commands::docker::inspect_container,
+            commands::docker::container_logs,
+            commands::docker::stream_container_stats,
+            commands::docker::start_searxng,
+            commands::docker::stop_searxng,
+            // Logging commands
+            commands::logging::query_logs,
+            commands::logging::export_logs,
+            // Crash reporting commands
+            commands::crash::list_crash_reports,
+            commands::crash::upload_crash_report,
+            // Background worker commands
+            commands::workers::list_workers,
+            commands::workers::pause_worker,
+            commands::workers::resume_worker,
+            commands::workers::cancel_worker,
         ])
         .on_window_event(|_window, event| {
             // Graceful shutdown
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::EnvFilter;
+
+use crate::config::LoggingConfig;
+
+static GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: String,
+    pub service: Option<String>,
+    pub message: String,
+}
+
+fn logs_dir() -> std::path::PathBuf {
+    crate::config::get_alfred_home().join("logs")
+}
+
+/// Initialize the tracing subscriber to write structured, leveled JSON events
+/// to a daily-rotating file under ALFRED_HOME/logs, then prune old log files.
+pub fn init(config: &LoggingConfig) {
+    let dir = logs_dir();
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+
+    let file_appender = tracing_appender::rolling::daily(&dir, "alfred.log");
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+
+    let filter = EnvFilter::try_new(&config.level).unwrap_or_else(|_| EnvFilter::new("info"));
+
+    let _ = tracing_subscriber::fmt()
+        .json()
+        .with_writer(non_blocking)
+        .with_env_filter(filter)
+        .try_init();
+
+    let _ = GUARD.set(guard);
+
+    prune_old_logs(config.retention_days);
+}
+
+/// Remove log files whose modification time is older than `retention_days`
+fn prune_old_logs(retention_days: u32) {
+    let Ok(entries) = std::fs::read_dir(logs_dir()) else {
+        return;
+    };
+    let cutoff = std::time::Duration::from_secs(retention_days as u64 * 24 * 60 * 60);
+    let now = std::time::SystemTime::now();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let Ok(modified) = metadata.modified() else {
+            continue;
+        };
+        if let Ok(age) = now.duration_since(modified) {
+            if age > cutoff {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+}
+
+/// Record a single line of output from a service (e.g. the Gateway child process)
+pub fn log_service_line(service: &str, line: &str) {
+    tracing::info!(target: "service", service = service, "{}", line);
+}
+
+/// Read and filter persisted log entries, most recent last
+pub fn query_logs(
+    level: Option<&str>,
+    since: Option<&str>,
+    limit: Option<usize>,
+    service: Option<&str>,
+) -> Vec<LogEntry> {
+    let mut entries = Vec::new();
+
+    let Ok(dir_entries) = std::fs::read_dir(logs_dir()) else {
+        return entries;
+    };
+
+    let mut files: Vec<_> = dir_entries.flatten().map(|e| e.path()).collect();
+    files.sort();
+
+    for path in files {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        for line in content.lines() {
+            if let Some(entry) = parse_log_line(line) {
+                if let Some(lvl) = level {
+                    if !entry.level.eq_ignore_ascii_case(lvl) {
+                        continue;
+                    }
+                }
+                if let Some(svc) = service {
+                    if entry.service.as_deref() != Some(svc) {
+                        continue;
+                    }
+                }
+                if let Some(since_ts) = since {
+                    if entry.timestamp.as_str() < since_ts {
+                        continue;
+                    }
+                }
+                entries.push(entry);
+            }
+        }
+    }
+
+    if let Some(limit) = limit {
+        let len = entries.len();
+        if len > limit {
+            entries = entries.split_off(len - limit);
+        }
+    }
+
+    entries
+}
+
+fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+
+    Some(LogEntry {
+        timestamp: value["timestamp"].as_str().unwrap_or_default().to_string(),
+        level: value["level"].as_str().unwrap_or("info").to_string(),
+        service: value["fields"]["service"].as_str().map(|s| s.to_string()),
+        message: value["fields"]["message"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// Concatenate all retained log files into a single export at `path`
+pub fn export_logs(path: &str) -> Result<(), String> {
+    let mut combined = String::new();
+
+    let dir_entries = std::fs::read_dir(logs_dir())
+        .map_err(|e| format!("Failed to read logs directory: {}", e))?;
+
+    let mut files: Vec<_> = dir_entries.flatten().map(|e| e.path()).collect();
+    files.sort();
+
+    for file in files {
+        if let Ok(content) = std::fs::read_to_string(&file) {
+            combined.push_str(&content);
+        }
+    }
+
+    std::fs::write(path, combined).map_err(|e| format!("Failed to export logs: {}", e))
+}
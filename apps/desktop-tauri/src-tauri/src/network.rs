@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use netstat2::{iterate_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+/// Where a connection's remote endpoint falls relative to this machine — used to
+/// ground the privacy score in what's actually talking to the outside world.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionScope {
+    Loopback,
+    Private,
+    Cloud,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConnectionEntry {
+    pub protocol: String,
+    pub local_addr: String,
+    pub local_port: u16,
+    pub remote_addr: String,
+    pub remote_port: u16,
+    pub pid: Option<u32>,
+    pub scope: ConnectionScope,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ConnectionSummary {
+    pub local_connections: u32,
+    pub cloud_connections: u32,
+    pub remote_hosts: Vec<String>,
+}
+
+/// Classify a remote address as loopback, private (LAN / link-local), or cloud (outbound)
+fn classify(addr: &IpAddr) -> ConnectionScope {
+    match addr {
+        IpAddr::V4(v4) => {
+            if v4.is_loopback() {
+                ConnectionScope::Loopback
+            } else if v4.is_private() || v4.is_link_local() {
+                ConnectionScope::Private
+            } else {
+                ConnectionScope::Cloud
+            }
+        }
+        IpAddr::V6(v6) => {
+            if v6.is_loopback() {
+                ConnectionScope::Loopback
+            } else if is_unique_local(v6) || v6.is_unicast_link_local() {
+                ConnectionScope::Private
+            } else {
+                ConnectionScope::Cloud
+            }
+        }
+    }
+}
+
+/// `Ipv6Addr::is_unique_local` isn't stable, so check the fc00::/7 range by hand
+fn is_unique_local(addr: &std::net::Ipv6Addr) -> bool {
+    (addr.octets()[0] & 0xfe) == 0xfc
+}
+
+/// Enumerate active TCP and UDP sockets on the system, tagged with the owning PID
+/// where the OS exposes one.
+pub fn list_connections() -> Result<Vec<ConnectionEntry>, String> {
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let proto_flags = ProtocolFlags::TCP | ProtocolFlags::UDP;
+
+    let sockets_info = iterate_sockets_info(af_flags, proto_flags)
+        .map_err(|e| format!("Failed to enumerate sockets: {}", e))?;
+
+    let mut entries = Vec::new();
+    for info in sockets_info {
+        let info = match info {
+            Ok(i) => i,
+            Err(_) => continue,
+        };
+        let pid = info.associated_pids.first().copied();
+
+        match info.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => {
+                entries.push(ConnectionEntry {
+                    protocol: "tcp".to_string(),
+                    local_addr: tcp.local_addr.to_string(),
+                    local_port: tcp.local_port,
+                    remote_addr: tcp.remote_addr.to_string(),
+                    remote_port: tcp.remote_port,
+                    pid,
+                    scope: classify(&tcp.remote_addr),
+                });
+            }
+            ProtocolSocketInfo::Udp(udp) => {
+                // A UDP socket that has called connect() reports a real remote endpoint
+                // (e.g. QUIC, DNS-over-UDP to a public resolver) — classify it the same
+                // way as TCP. An unconnected listener reports 0.0.0.0:0, which stays local.
+                let has_remote = udp.remote_port != 0;
+                entries.push(ConnectionEntry {
+                    protocol: "udp".to_string(),
+                    local_addr: udp.local_addr.to_string(),
+                    local_port: udp.local_port,
+                    remote_addr: udp.remote_addr.to_string(),
+                    remote_port: udp.remote_port,
+                    pid,
+                    scope: if has_remote {
+                        classify(&udp.remote_addr)
+                    } else {
+                        ConnectionScope::Loopback
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Roll a connection table up into the counts and host list the privacy score needs
+pub fn summarize(entries: &[ConnectionEntry]) -> ConnectionSummary {
+    let mut local_connections = 0;
+    let mut cloud_connections = 0;
+    let mut remote_hosts = HashSet::new();
+
+    for entry in entries {
+        match entry.scope {
+            ConnectionScope::Loopback | ConnectionScope::Private => local_connections += 1,
+            ConnectionScope::Cloud => {
+                cloud_connections += 1;
+                remote_hosts.insert(entry.remote_addr.clone());
+            }
+        }
+    }
+
+    ConnectionSummary {
+        local_connections,
+        cloud_connections,
+        remote_hosts: remote_hosts.into_iter().collect(),
+    }
+}
+
+/// Outbound-connection summary for Alfred's own processes only — the Gateway child and
+/// any Alfred-managed containers — so a browser or other unrelated app talking to the
+/// cloud doesn't drag down the privacy score.
+pub async fn current_summary(gateway_state: &crate::gateway::GatewayState) -> ConnectionSummary {
+    let mut tracked_pids: HashSet<u32> = HashSet::new();
+    tracked_pids.extend(crate::gateway::gateway_pids(gateway_state).await);
+    let container_names: Vec<String> = crate::docker::list_containers(None)
+        .await
+        .map(|containers| containers.into_iter().map(|c| c.name).collect())
+        .unwrap_or_default();
+    for name in container_names {
+        if let Ok(detail) = crate::docker::inspect_container(&name).await {
+            if let Some(pid) = detail.pid {
+                tracked_pids.insert(pid);
+            }
+        }
+    }
+
+    if tracked_pids.is_empty() {
+        return ConnectionSummary::default();
+    }
+
+    let entries = match list_connections() {
+        Ok(entries) => entries,
+        Err(_) => return ConnectionSummary::default(),
+    };
+
+    let tracked: Vec<ConnectionEntry> = entries
+        .into_iter()
+        .filter(|e| e.pid.map(|pid| tracked_pids.contains(&pid)).unwrap_or(false))
+        .collect();
+
+    summarize(&tracked)
+}
@@ -2,6 +2,16 @@ use serde::{Deserialize, Serialize};
 
 const OLLAMA_BASE: &str = "http://localhost:11434";
 
+#[derive(Debug, Serialize, Clone)]
+pub struct PullProgressEvent {
+    pub model: String,
+    pub status: String,
+    pub completed: u64,
+    pub total: u64,
+    pub percent: f32,
+    pub bytes_per_sec: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OllamaModel {
     pub name: String,
@@ -67,14 +77,18 @@ pub async fn list_models() -> Result<Vec<OllamaModel>, String> {
     Ok(tags.models)
 }
 
-/// Pull (download) a model by name with streaming progress
-pub async fn pull_model(name: &str) -> Result<String, String> {
+/// Open the streamed NDJSON body of an `/api/pull` request. Kept as a raw byte stream
+/// rather than driven to completion here so a `workers::OllamaPullWorker` can advance it
+/// one NDJSON progress line per `step()` call and stay cancellable/pausable.
+pub async fn open_pull_stream(
+    name: &str,
+) -> Result<std::pin::Pin<Box<dyn futures_util::Stream<Item = reqwest::Result<bytes::Bytes>> + Send>>, String> {
     let client = reqwest::Client::new();
     let url = format!("{}/api/pull", OLLAMA_BASE);
 
     let body = serde_json::json!({
         "name": name,
-        "stream": false
+        "stream": true
     });
 
     let resp = client
@@ -84,13 +98,32 @@ pub async fn pull_model(name: &str) -> Result<String, String> {
         .await
         .map_err(|e| format!("Failed to pull model: {}", e))?;
 
-    if resp.status().is_success() {
-        Ok(format!("Successfully pulled model: {}", name))
-    } else {
+    if !resp.status().is_success() {
         let status = resp.status();
         let text = resp.text().await.unwrap_or_default();
-        Err(format!("Failed to pull model ({}): {}", status, text))
+        return Err(format!("Failed to pull model ({}): {}", status, text));
     }
+
+    Ok(Box::pin(resp.bytes_stream()))
+}
+
+/// Confirm Ollama recorded a digest for the freshly-pulled model. `/api/show` has no
+/// top-level `digest` field to compare against, and the per-layer digests streamed during
+/// the pull are blob digests, not the model (manifest) digest — `/api/tags` is the only
+/// place a comparable, whole-model digest actually lives, which is also where
+/// `OllamaModel::digest` is parsed from.
+pub(crate) async fn verify_digest(name: &str) -> Result<(), String> {
+    let models = list_models().await?;
+    let model = models
+        .iter()
+        .find(|m| m.name == name)
+        .ok_or_else(|| format!("Digest verification failed: {} not found in /api/tags after pull", name))?;
+
+    if model.digest.trim().is_empty() {
+        return Err(format!("Digest verification failed: Ollama recorded no digest for {}", name));
+    }
+
+    Ok(())
 }
 
 /// Delete a model by name
@@ -0,0 +1,109 @@
+#![cfg(feature = "lua-services")]
+
+use mlua::{Function, Lua, Table};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A service declared by a Lua file under `ALFRED_HOME/services/*.lua`, so users can
+/// register self-hosted containers without patching `docker.rs`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScriptedService {
+    pub name: String,
+    pub spec: crate::docker::ContainerSpec,
+    pub script_path: PathBuf,
+}
+
+/// Enumerate and parse every `*.lua` service definition in `ALFRED_HOME/services`.
+/// A script that fails to load is logged and skipped rather than aborting the rest.
+pub fn load_services() -> Vec<ScriptedService> {
+    let dir = crate::config::get_alfred_home().join("services");
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut services = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("lua") {
+            continue;
+        }
+
+        match load_service(&path) {
+            Ok(service) => services.push(service),
+            Err(e) => eprintln!("[lua-services] Failed to load {}: {}", path.display(), e),
+        }
+    }
+
+    services
+}
+
+fn load_service(path: &Path) -> Result<ScriptedService, String> {
+    let lua = eval_script(path)?;
+
+    let service_table: Table = lua
+        .globals()
+        .get("service")
+        .map_err(|e| format!("{} does not define a `service` table: {}", path.display(), e))?;
+
+    let name: String = service_table
+        .get("name")
+        .map_err(|e| format!("{}: missing `name`: {}", path.display(), e))?;
+    let image: String = service_table
+        .get("image")
+        .map_err(|e| format!("{}: missing `image`: {}", path.display(), e))?;
+    let ports: Vec<String> = service_table.get("ports").unwrap_or_default();
+    let env: Vec<String> = service_table.get("env").unwrap_or_default();
+    let volumes: Vec<String> = service_table.get("volumes").unwrap_or_default();
+    let restart_policy: String = service_table
+        .get("restart_policy")
+        .unwrap_or_else(|_| "unless-stopped".to_string());
+
+    Ok(ScriptedService {
+        spec: crate::docker::ContainerSpec {
+            name: format!("alfred-{}", name),
+            image,
+            ports,
+            env,
+            volumes,
+            restart_policy,
+        },
+        name,
+        script_path: path.to_path_buf(),
+    })
+}
+
+fn eval_script(path: &Path) -> Result<Lua, String> {
+    let source = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let lua = Lua::new();
+    lua.load(&source)
+        .exec()
+        .map_err(|e| format!("Failed to evaluate {}: {}", path.display(), e))?;
+    Ok(lua)
+}
+
+/// Run a scripted service's optional `health()` hook. Lua's VM is re-evaluated on a
+/// blocking thread each call since `mlua::Lua` isn't `Send` and can't be held across
+/// an `.await` point.
+pub async fn run_health_hook(service: &ScriptedService) -> Option<bool> {
+    let path = service.script_path.clone();
+    tokio::task::spawn_blocking(move || -> Option<bool> {
+        let lua = eval_script(&path).ok()?;
+        let health: Function = lua.globals().get("health").ok()?;
+        health.call::<bool>(()).ok()
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Run a scripted service's optional `on_start()` hook, ignoring errors — a hook is a
+/// side-effecting convenience, not something that should block the container from starting
+pub async fn run_on_start_hook(service: &ScriptedService) {
+    let path = service.script_path.clone();
+    let _ = tokio::task::spawn_blocking(move || -> Option<()> {
+        let lua = eval_script(&path).ok()?;
+        let hook: Function = lua.globals().get("on_start").ok()?;
+        hook.call::<()>(()).ok()
+    })
+    .await;
+}
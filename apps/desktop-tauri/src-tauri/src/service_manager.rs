@@ -0,0 +1,75 @@
+use service_manager::{
+    ServiceInstallCtx, ServiceLabel, ServiceManager, ServiceStartCtx, ServiceStopCtx,
+    ServiceUninstallCtx,
+};
+use std::str::FromStr;
+
+const SERVICE_LABEL: &str = "com.alfred.gateway";
+
+fn label() -> Result<ServiceLabel, String> {
+    ServiceLabel::from_str(SERVICE_LABEL).map_err(|e| format!("Invalid service label: {}", e))
+}
+
+fn manager() -> Result<Box<dyn ServiceManager>, String> {
+    <dyn ServiceManager>::native().map_err(|e| format!("Failed to get service manager: {}", e))
+}
+
+/// Marker file tracking whether the Gateway is registered as a native service
+fn marker_path() -> std::path::PathBuf {
+    crate::config::get_alfred_home().join("gateway.service.installed")
+}
+
+/// Register the Gateway under the platform-native service manager (launchd/systemd/SCM)
+pub fn install_service() -> Result<(), String> {
+    let mgr = manager()?;
+    let alfred_home = crate::config::get_alfred_home();
+    let gateway_path = alfred_home.join("gateway").join("src").join("index.ts");
+
+    mgr.install(ServiceInstallCtx {
+        label: label()?,
+        program: "npx".into(),
+        args: vec!["tsx".into(), gateway_path.into()],
+        contents: None,
+        username: None,
+        working_directory: Some(alfred_home.clone()),
+        environment: Some(vec![(
+            "ALFRED_HOME".to_string(),
+            alfred_home.to_string_lossy().to_string(),
+        )]),
+        autostart: true,
+        disable_restart_on_failure: false,
+    })
+    .map_err(|e| format!("Failed to install service: {}", e))?;
+
+    std::fs::write(marker_path(), "installed")
+        .map_err(|e| format!("Failed to record service install state: {}", e))
+}
+
+/// Remove the Gateway service registration
+pub fn uninstall_service() -> Result<(), String> {
+    let mgr = manager()?;
+    mgr.uninstall(ServiceUninstallCtx { label: label()? })
+        .map_err(|e| format!("Failed to uninstall service: {}", e))?;
+
+    let _ = std::fs::remove_file(marker_path());
+    Ok(())
+}
+
+/// Start the installed Gateway service
+pub fn start_service() -> Result<(), String> {
+    let mgr = manager()?;
+    mgr.start(ServiceStartCtx { label: label()? })
+        .map_err(|e| format!("Failed to start service: {}", e))
+}
+
+/// Stop the installed Gateway service
+pub fn stop_service() -> Result<(), String> {
+    let mgr = manager()?;
+    mgr.stop(ServiceStopCtx { label: label()? })
+        .map_err(|e| format!("Failed to stop service: {}", e))
+}
+
+/// Check whether the Gateway is registered as a native service
+pub fn service_installed() -> bool {
+    marker_path().exists()
+}
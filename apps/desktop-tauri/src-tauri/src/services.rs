@@ -1,20 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ServiceStatus {
     pub name: String,
     pub running: bool,
     pub port: Option<u16>,
     pub health: String,
     pub details: Option<String>,
+    #[serde(default)]
+    pub container_id: Option<String>,
+    #[serde(default)]
+    pub image: Option<String>,
 }
 
-/// Auto-start services on app launch
-pub async fn auto_start(gateway_state: &crate::gateway::GatewayState) -> Vec<ServiceStatus> {
-    let mut statuses = Vec::new();
-
-    // Start Gateway
-    match crate::gateway::start_gateway(gateway_state).await {
+/// Spawn the Gateway as an in-process child and push its resulting status
+async fn push_spawned_gateway_status(
+    gateway_state: &crate::gateway::GatewayState,
+    app: &tauri::AppHandle,
+    statuses: &mut Vec<ServiceStatus>,
+) {
+    match crate::gateway::start_gateway(gateway_state, app).await {
         Ok(_) => {
             statuses.push(ServiceStatus {
                 name: "Gateway".to_string(),
@@ -22,6 +27,7 @@ pub async fn auto_start(gateway_state: &crate::gateway::GatewayState) -> Vec<Ser
                 port: Some(18789),
                 health: "starting".to_string(),
                 details: Some("Gateway process started".to_string()),
+                ..Default::default()
             });
         }
         Err(e) => {
@@ -31,9 +37,114 @@ pub async fn auto_start(gateway_state: &crate::gateway::GatewayState) -> Vec<Ser
                 port: Some(18789),
                 health: "error".to_string(),
                 details: Some(e),
+                ..Default::default()
             });
         }
     }
+}
+
+/// Start every user-defined Lua service under ALFRED_HOME/services, pushing a status
+/// for each regardless of outcome
+#[cfg(feature = "lua-services")]
+async fn start_scripted_services(statuses: &mut Vec<ServiceStatus>) {
+    for service in crate::scripting::load_services() {
+        match crate::docker::start_container(&service.spec).await {
+            Ok(_) => {
+                crate::scripting::run_on_start_hook(&service).await;
+                statuses.push(ServiceStatus {
+                    name: service.name,
+                    running: true,
+                    health: "starting".to_string(),
+                    details: Some(format!("Started from {}", service.script_path.display())),
+                    ..Default::default()
+                });
+            }
+            Err(e) => {
+                statuses.push(ServiceStatus {
+                    name: service.name,
+                    running: false,
+                    health: "error".to_string(),
+                    details: Some(e),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "lua-services"))]
+async fn start_scripted_services(_statuses: &mut Vec<ServiceStatus>) {}
+
+/// Check each user-defined Lua service's running state, consulting its `health()` hook
+/// when it defines one
+#[cfg(feature = "lua-services")]
+async fn check_scripted_services(statuses: &mut Vec<ServiceStatus>) {
+    for service in crate::scripting::load_services() {
+        let running = crate::docker::inspect_container(&service.spec.name)
+            .await
+            .map(|d| d.running)
+            .unwrap_or(false);
+
+        let healthy = if running {
+            crate::scripting::run_health_hook(&service).await.unwrap_or(running)
+        } else {
+            false
+        };
+
+        statuses.push(ServiceStatus {
+            name: service.name,
+            running,
+            health: if healthy { "healthy" } else { "unhealthy" }.to_string(),
+            ..Default::default()
+        });
+    }
+}
+
+#[cfg(not(feature = "lua-services"))]
+async fn check_scripted_services(_statuses: &mut Vec<ServiceStatus>) {}
+
+/// Auto-start services on app launch
+pub async fn auto_start(
+    gateway_state: &crate::gateway::GatewayState,
+    app: &tauri::AppHandle,
+) -> Vec<ServiceStatus> {
+    let mut statuses = Vec::new();
+
+    let auto_start_enabled = crate::config::read_config()
+        .map(|c| c.gateway.auto_start)
+        .unwrap_or(false);
+
+    if auto_start_enabled {
+        // auto_start means the Gateway should survive app restarts and reboots,
+        // so install it as a native service instead of only spawning a child.
+        if !crate::service_manager::service_installed() {
+            if let Err(e) = crate::service_manager::install_service() {
+                eprintln!("[startup] Failed to install Gateway service: {}", e);
+            }
+        }
+
+        match crate::service_manager::start_service() {
+            Ok(_) => {
+                statuses.push(ServiceStatus {
+                    name: "Gateway".to_string(),
+                    running: true,
+                    port: Some(18789),
+                    health: "starting".to_string(),
+                    details: Some("Gateway service started".to_string()),
+                    ..Default::default()
+                });
+            }
+            Err(e) => {
+                eprintln!(
+                    "[startup] Failed to start Gateway service, falling back to child process: {}",
+                    e
+                );
+                push_spawned_gateway_status(gateway_state, app, &mut statuses).await;
+            }
+        }
+    } else {
+        push_spawned_gateway_status(gateway_state, app, &mut statuses).await;
+    }
 
     // Check Ollama status (don't start it, just detect)
     let ollama_running = crate::ollama::detect_ollama().await;
@@ -42,9 +153,12 @@ pub async fn auto_start(gateway_state: &crate::gateway::GatewayState) -> Vec<Ser
         running: ollama_running,
         port: Some(11434),
         health: if ollama_running { "healthy" } else { "not running" }.to_string(),
-        details: None,
+        ..Default::default()
     });
 
+    // User-defined containers from ALFRED_HOME/services/*.lua
+    start_scripted_services(&mut statuses).await;
+
     statuses
 }
 
@@ -59,7 +173,7 @@ pub async fn check_all_services(gateway_state: &crate::gateway::GatewayState) ->
         running: gw_running,
         port: Some(18789),
         health: if gw_running { "healthy" } else { "not running" }.to_string(),
-        details: None,
+        ..Default::default()
     });
 
     // Ollama
@@ -69,28 +183,44 @@ pub async fn check_all_services(gateway_state: &crate::gateway::GatewayState) ->
         running: ollama_running,
         port: Some(11434),
         health: if ollama_running { "healthy" } else { "not running" }.to_string(),
-        details: None,
+        ..Default::default()
     });
 
     // Docker
-    let docker_available = crate::docker::is_docker_available();
+    let docker_available = crate::docker::is_docker_available().await;
     statuses.push(ServiceStatus {
         name: "Docker".to_string(),
         running: docker_available,
-        port: None,
         health: if docker_available { "available" } else { "not installed" }.to_string(),
-        details: None,
+        ..Default::default()
     });
 
     // SearXNG
-    let searxng_running = crate::docker::is_searxng_running();
-    statuses.push(ServiceStatus {
-        name: "SearXNG".to_string(),
-        running: searxng_running,
-        port: Some(8888),
-        health: if searxng_running { "healthy" } else { "not running" }.to_string(),
-        details: None,
-    });
+    match crate::docker::inspect_container("alfred-searxng").await {
+        Ok(detail) => {
+            statuses.push(ServiceStatus {
+                name: "SearXNG".to_string(),
+                running: detail.running,
+                port: Some(8888),
+                health: if detail.running { "healthy" } else { "not running" }.to_string(),
+                container_id: Some(detail.id),
+                image: Some(detail.image),
+                ..Default::default()
+            });
+        }
+        Err(_) => {
+            statuses.push(ServiceStatus {
+                name: "SearXNG".to_string(),
+                running: false,
+                port: Some(8888),
+                health: "not running".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    // User-defined containers from ALFRED_HOME/services/*.lua
+    check_scripted_services(&mut statuses).await;
 
     statuses
 }
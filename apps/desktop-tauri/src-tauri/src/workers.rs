@@ -0,0 +1,320 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum StepOutcome {
+    Idle,
+    Progress(f32),
+    Done,
+    Error(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorkerState {
+    Active,
+    Idle,
+    Dead,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WorkerControl {
+    Start,
+    Pause,
+    Resume,
+    Cancel,
+}
+
+/// A unit of cancellable, pausable background work driven by repeated `step()` calls
+#[async_trait]
+pub trait Worker: Send {
+    fn name(&self) -> String;
+    async fn step(&mut self) -> StepOutcome;
+}
+
+struct WorkerEntry {
+    name: String,
+    state: Arc<Mutex<WorkerState>>,
+    progress: Arc<Mutex<f32>>,
+    last_error: Arc<Mutex<Option<String>>>,
+    control_tx: mpsc::Sender<WorkerControl>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerState,
+    pub progress: f32,
+    pub last_error: Option<String>,
+}
+
+/// Tranquility: how long a worker sleeps between iterations while idle
+#[derive(Debug, Clone, Copy)]
+pub struct Tranquility(pub Duration);
+
+impl Default for Tranquility {
+    fn default() -> Self {
+        Tranquility(Duration::from_millis(500))
+    }
+}
+
+impl Tranquility {
+    /// Read the configured idle sleep from `alfred.json`, falling back to the default
+    pub fn from_config() -> Self {
+        let ms = crate::config::read_config()
+            .map(|c| c.workers.tranquility_ms)
+            .unwrap_or(500);
+        Tranquility(Duration::from_millis(ms))
+    }
+}
+
+pub type WorkerRegistry = Arc<Mutex<HashMap<String, WorkerEntry>>>;
+
+pub fn create_registry() -> WorkerRegistry {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+/// Register a worker and drive it to completion (or cancellation) on a background task
+pub async fn spawn_worker(
+    registry: &WorkerRegistry,
+    mut worker: Box<dyn Worker>,
+    tranquility: Tranquility,
+) {
+    let name = worker.name();
+    let state = Arc::new(Mutex::new(WorkerState::Idle));
+    let progress = Arc::new(Mutex::new(0.0));
+    let last_error = Arc::new(Mutex::new(None));
+    let (control_tx, mut control_rx) = mpsc::channel(8);
+
+    {
+        let mut reg = registry.lock().await;
+        reg.insert(
+            name.clone(),
+            WorkerEntry {
+                name: name.clone(),
+                state: state.clone(),
+                progress: progress.clone(),
+                last_error: last_error.clone(),
+                control_tx: control_tx.clone(),
+            },
+        );
+    }
+
+    tokio::spawn(async move {
+        let mut paused = false;
+
+        loop {
+            // Drain any pending control messages without blocking the work loop
+            while let Ok(ctrl) = control_rx.try_recv() {
+                match ctrl {
+                    WorkerControl::Pause => paused = true,
+                    WorkerControl::Resume | WorkerControl::Start => paused = false,
+                    WorkerControl::Cancel => {
+                        *state.lock().await = WorkerState::Dead;
+                        *last_error.lock().await = Some("Cancelled".to_string());
+                        return;
+                    }
+                }
+            }
+
+            if paused {
+                // Block until a control message arrives so a paused worker costs nothing
+                match control_rx.recv().await {
+                    Some(WorkerControl::Cancel) => {
+                        *state.lock().await = WorkerState::Dead;
+                        *last_error.lock().await = Some("Cancelled".to_string());
+                        return;
+                    }
+                    Some(WorkerControl::Resume) | Some(WorkerControl::Start) => paused = false,
+                    _ => continue,
+                }
+            }
+
+            match worker.step().await {
+                StepOutcome::Progress(p) => {
+                    *state.lock().await = WorkerState::Active;
+                    *progress.lock().await = p;
+                }
+                StepOutcome::Idle => {
+                    *state.lock().await = WorkerState::Idle;
+                    tokio::time::sleep(tranquility.0).await;
+                }
+                StepOutcome::Done => {
+                    *state.lock().await = WorkerState::Dead;
+                    *progress.lock().await = 100.0;
+                    return;
+                }
+                StepOutcome::Error(e) => {
+                    *state.lock().await = WorkerState::Dead;
+                    *last_error.lock().await = Some(e);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Send a control message to a running worker by name
+pub async fn send_control(registry: &WorkerRegistry, name: &str, ctrl: WorkerControl) -> Result<(), String> {
+    let reg = registry.lock().await;
+    let entry = reg.get(name).ok_or_else(|| format!("No worker named {}", name))?;
+    entry
+        .control_tx
+        .send(ctrl)
+        .await
+        .map_err(|e| format!("Failed to send control to worker {}: {}", name, e))
+}
+
+/// Snapshot the state of every registered worker
+pub async fn list_workers(registry: &WorkerRegistry) -> Vec<WorkerStatus> {
+    let reg = registry.lock().await;
+    let mut statuses = Vec::new();
+    for entry in reg.values() {
+        statuses.push(WorkerStatus {
+            name: entry.name.clone(),
+            state: *entry.state.lock().await,
+            progress: *entry.progress.lock().await,
+            last_error: entry.last_error.lock().await.clone(),
+        });
+    }
+    statuses
+}
+
+type ByteStream = Pin<Box<dyn futures_util::Stream<Item = reqwest::Result<bytes::Bytes>> + Send>>;
+
+/// Drives an Ollama model pull one streamed progress line at a time so it can report progress
+/// and be paused/cancelled like any other worker.
+pub struct OllamaPullWorker {
+    model: String,
+    app: tauri::AppHandle,
+    stream: ByteStream,
+    buf: String,
+    last_completed: u64,
+    last_tick: std::time::Instant,
+}
+
+impl OllamaPullWorker {
+    pub async fn new(app: tauri::AppHandle, model: String) -> Result<Self, String> {
+        let stream = crate::ollama::open_pull_stream(&model).await?;
+        Ok(Self {
+            model,
+            app,
+            stream,
+            buf: String::new(),
+            last_completed: 0,
+            last_tick: std::time::Instant::now(),
+        })
+    }
+}
+
+#[async_trait]
+impl Worker for OllamaPullWorker {
+    fn name(&self) -> String {
+        format!("pull:{}", self.model)
+    }
+
+    async fn step(&mut self) -> StepOutcome {
+        use futures_util::StreamExt;
+
+        while !self.buf.contains('\n') {
+            match self.stream.next().await {
+                Some(Ok(chunk)) => self.buf.push_str(&String::from_utf8_lossy(&chunk)),
+                Some(Err(e)) => return StepOutcome::Error(format!("Pull stream error: {}", e)),
+                None => return self.finish().await,
+            }
+        }
+
+        let pos = match self.buf.find('\n') {
+            Some(p) => p,
+            None => return StepOutcome::Idle,
+        };
+        let line: String = self.buf.drain(..=pos).collect();
+        if line.trim().is_empty() {
+            return StepOutcome::Idle;
+        }
+
+        let progress: crate::ollama::PullProgress = match serde_json::from_str(&line) {
+            Ok(p) => p,
+            Err(e) => return StepOutcome::Error(format!("Failed to parse pull progress: {}", e)),
+        };
+
+        let completed = progress.completed.unwrap_or(0);
+        let total = progress.total.unwrap_or(0);
+        let elapsed = self.last_tick.elapsed().as_secs_f64().max(0.001);
+        let bytes_per_sec = completed.saturating_sub(self.last_completed) as f64 / elapsed;
+        self.last_completed = completed;
+        self.last_tick = std::time::Instant::now();
+
+        let percent = if total > 0 {
+            (completed as f32 / total as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        let _ = tauri::Emitter::emit(
+            &self.app,
+            "pull-progress",
+            crate::ollama::PullProgressEvent {
+                model: self.model.clone(),
+                status: progress.status,
+                completed,
+                total,
+                percent,
+                bytes_per_sec,
+            },
+        );
+
+        StepOutcome::Progress(percent)
+    }
+}
+
+impl OllamaPullWorker {
+    async fn finish(&self) -> StepOutcome {
+        let verify_digests = crate::config::read_config()
+            .map(|c| c.models.verify_digests)
+            .unwrap_or(false);
+
+        if verify_digests {
+            if let Err(e) = crate::ollama::verify_digest(&self.model).await {
+                return StepOutcome::Error(e);
+            }
+        }
+
+        StepOutcome::Done
+    }
+}
+
+/// A single-shot worker that provisions (creates and starts) a Docker container
+pub struct ContainerProvisionWorker {
+    spec: crate::docker::ContainerSpec,
+    done: bool,
+}
+
+impl ContainerProvisionWorker {
+    pub fn new(spec: crate::docker::ContainerSpec) -> Self {
+        Self { spec, done: false }
+    }
+}
+
+#[async_trait]
+impl Worker for ContainerProvisionWorker {
+    fn name(&self) -> String {
+        format!("provision:{}", self.spec.name)
+    }
+
+    async fn step(&mut self) -> StepOutcome {
+        if self.done {
+            return StepOutcome::Done;
+        }
+        self.done = true;
+
+        match crate::docker::start_container(&self.spec).await {
+            Ok(_) => StepOutcome::Done,
+            Err(e) => StepOutcome::Error(e),
+        }
+    }
+}